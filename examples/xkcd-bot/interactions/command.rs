@@ -89,7 +89,7 @@ impl XkcdLatestCommand {
 pub struct XkcdNumberCommand {
     /// Comic number
     #[command(min_value = 1, desc_localizations = "xkcd_number_arg_desc")]
-    pub number: i64,
+    pub number: u32,
 }
 
 fn xkcd_number_desc() -> DescLocalizations {
@@ -106,7 +106,7 @@ fn xkcd_number_arg_desc() -> DescLocalizations {
 impl XkcdNumberCommand {
     /// Run the `/xkcd number <num>` command.
     pub async fn run(&self, interaction: Interaction, client: &Client) -> anyhow::Result<()> {
-        let comic = XkcdComic::get_number(self.number.try_into()?).await?;
+        let comic = XkcdComic::get_number(self.number).await?;
 
         let mut data = InteractionResponseDataBuilder::new();
         if let Some(comic) = comic {
@@ -1,29 +1,116 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{DeriveInput, Error, FieldsNamed, Result};
+use syn::{DeriveInput, Error, FieldsNamed, Generics, Result};
 
-use super::parse::{FieldType, StructField, TypeAttribute};
-use crate::{
-    command::model::parse::{channel_type, command_option_value},
-    parse::syntax::{find_attr, optional},
+use super::{
+    companion,
+    constraints::Constraint,
+    parse::{FieldList, FieldType, StructField, TypeAttribute},
 };
+use crate::parse::syntax::find_attr;
 
 /// Implementation of `CommandModel` derive macro
 pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Result<TokenStream> {
-    let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
     let fields = match fields {
         Some(fields) => StructField::from_fields(fields)?,
         None => Vec::new(),
     };
 
-    let autocomplete = match find_attr(&input.attrs, "command") {
-        Some(attr) => TypeAttribute::parse(attr)?.autocomplete.unwrap_or(false),
-        None => false,
+    let (autocomplete, groups, autocomplete_model) = match find_attr(&input.attrs, "command") {
+        Some(attr) => {
+            let attributes = TypeAttribute::parse(attr)?;
+            let autocomplete = attributes.autocomplete.unwrap_or(false);
+
+            if autocomplete && attributes.autocomplete_model.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "`autocomplete_model` cannot be used on autocomplete models",
+                ));
+            }
+
+            (
+                autocomplete,
+                attributes.one_of,
+                attributes.autocomplete_model,
+            )
+        }
+        None => (false, Vec::new(), None),
     };
 
-    for field in &fields {
+    let mut tokens = model_impl(
+        &input.ident,
+        &input.generics,
+        &fields,
+        autocomplete,
+        &groups,
+    )?;
+
+    if let Some(name) = autocomplete_model {
+        tokens.extend(companion::autocomplete_model(&input, name, fields)?);
+    }
+
+    Ok(tokens)
+}
+
+/// Generate the `CommandModel` implementation of a model
+pub fn model_impl(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &[StructField],
+    autocomplete: bool,
+    groups: &[FieldList],
+) -> Result<TokenStream> {
+    let where_clause = &generics.where_clause;
+    let (body, constraints_const) = model_body(
+        fields,
+        autocomplete,
+        groups,
+        generics,
+        ident.span(),
+        quote!(Self),
+    )?;
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
+            #constraints_const
+
+            fn from_interaction(
+                __data: ::twilight_interactions::command::CommandInputData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                Self::from_interaction_with(__data, &::std::default::Default::default())
+            }
+
+            fn from_interaction_with(
+                __data: ::twilight_interactions::command::CommandInputData,
+                __context: &::twilight_interactions::command::CommandContext,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Generate the parsing code of a model from `__data` and `__context`
+///
+/// This is also used for inline subcommand variants, with the variant path as
+/// constructor. The `CONSTRAINTS` constant is returned separately.
+pub fn model_body(
+    fields: &[StructField],
+    autocomplete: bool,
+    groups: &[FieldList],
+    generics: &Generics,
+    span: Span,
+    constructor: TokenStream,
+) -> Result<(TokenStream, TokenStream)> {
+    let constraints = Constraint::from_fields(fields, groups)?;
+    if autocomplete && !constraints.is_empty() {
+        return Err(Error::new(
+            span,
+            "option relationships are not supported on autocomplete models",
+        ));
+    }
+
+    for field in fields {
         // If autocomplete, ensure all fields are either `AutocompleteValue` or `Option`s
         if autocomplete && ![FieldType::Autocomplete, FieldType::Optional].contains(&field.kind) {
             return Err(Error::new(
@@ -41,67 +128,159 @@ pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Re
         }
     }
 
+    let settings_check = settings_check(fields, generics);
     let field_unknown = field_unknown(autocomplete);
     let fields_init = fields.iter().map(field_init);
     let fields_match_arms = fields.iter().map(field_match_arm);
     let fields_constructor = fields.iter().map(field_constructor);
+    let constraints_check = constraints.iter().map(Constraint::check);
+    let constraints_const = if constraints.is_empty() {
+        TokenStream::new()
+    } else {
+        let items = constraints.iter().map(Constraint::metadata);
+        quote! {
+            const CONSTRAINTS: &'static [::twilight_interactions::command::OptionConstraint] = &[#(#items),*];
+        }
+    };
 
-    Ok(quote! {
-        impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
-            fn from_interaction(
-                __data: ::twilight_interactions::command::CommandInputData,
-            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
-                #(#fields_init)*
+    let body = quote! {
+        #settings_check
 
-                for __opt in __data.options {
-                    match &*__opt.name {
-                        #(#fields_match_arms,)*
-                        __other => #field_unknown
-                    }
-                }
+        #(#fields_init)*
 
-                ::std::result::Result::Ok(Self { #(#fields_constructor),* })
+        for __opt in __data.options {
+            match &*__opt.name {
+                #(#fields_match_arms,)*
+                __other => #field_unknown
             }
         }
-    })
+
+        #(#constraints_check)*
+
+        ::std::result::Result::Ok(#constructor { #(#fields_constructor),* })
+    };
+
+    Ok((body, constraints_const))
+}
+
+/// Generate the check of the parsing settings of fields
+///
+/// Settings not read by the field type are rejected at compile time. The
+/// check is a `const` item for non-generic models, so that it also runs with
+/// `cargo check`, and an inline `const` block otherwise.
+fn settings_check(fields: &[StructField], generics: &Generics) -> TokenStream {
+    let checks = fields
+        .iter()
+        .flat_map(|field| {
+            let settings = &field.attributes.settings;
+            // `min_items` is the number of required options of repeated fields
+            let items = settings.max_items.is_some()
+                || (settings.min_items.is_some() && field.attributes.repeat.is_none());
+
+            [
+                ("formats", "FORMATS", settings.formats.is_some()),
+                ("separator", "SEPARATOR", settings.separator.is_some()),
+                ("min_items` and `max_items", "ITEMS", items),
+                ("same_guild", "SAME_GUILD", settings.same_guild),
+            ]
+            .into_iter()
+            .filter(|(_, _, used)| *used)
+            .map(move |(name, setting, _)| {
+                let span = field.span;
+                let ty = &field.ty;
+                let setting = Ident::new(setting, span);
+                let message = format!(
+                    "`{name}` is not read by the type of field `{}`",
+                    field.ident
+                );
+
+                quote_spanned! {span=>
+                    ::twilight_interactions::command::internal::check_setting(
+                        <#ty as ::twilight_interactions::command::CommandOption>::SETTINGS,
+                        ::twilight_interactions::command::internal::SupportedSettings::#setting,
+                        #message,
+                    );
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        TokenStream::new()
+    } else if generics.params.is_empty() {
+        quote!(const _: () = { #(#checks)* };)
+    } else {
+        quote!(const { #(#checks)* };)
+    }
 }
 
 /// Generate field initialization variables
 fn field_init(field: &StructField) -> TokenStream {
     let ident = &field.ident;
-    quote!(let mut #ident = None;)
+    let ty = &field.ty;
+
+    match &field.attributes.repeat {
+        Some(repeat) => {
+            let count = usize::from(repeat.inner);
+            quote! {
+                let mut #ident: [::std::option::Option<#ty>; #count] =
+                    ::std::array::from_fn(|_| ::std::option::Option::None);
+            }
+        }
+        None => quote!(let mut #ident = None;),
+    }
 }
 
 /// Generate field match arm
+///
+/// Repeated fields have one match arm per option.
 fn field_match_arm(field: &StructField) -> TokenStream {
     let ident = &field.ident;
-    let span = field.span;
+    let repeated = field.attributes.repeat.is_some();
 
-    let name = field.attributes.name_default(ident.to_string());
-    let max_value = command_option_value(field.attributes.max_value);
-    let min_value = command_option_value(field.attributes.min_value);
-    let max_length = optional(field.attributes.max_length);
-    let min_length = optional(field.attributes.min_length);
+    let arms = field
+        .option_names()
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let target = if repeated {
+                quote!(#ident[#index])
+            } else {
+                quote!(#ident)
+            };
 
-    let channel_types = if field.attributes.channel_types.is_empty() {
-        quote! { ::std::option::Option::None }
-    } else {
-        let items = field.attributes.channel_types.iter().map(channel_type);
-        quote! { ::std::option::Option::Some(::std::vec![#(#items),*]) }
+            option_match_arm(field, &name, target)
+        });
+
+    quote!(#(#arms),*)
+}
+
+/// Generate the match arm of a single option
+fn option_match_arm(field: &StructField, name: &str, target: TokenStream) -> TokenStream {
+    let span = field.span;
+    let ty = &field.ty;
+    let option_data = field.attributes.data.to_tokens(
+        quote!(<#ty as ::twilight_interactions::command::CommandOption>::default_data()),
+    );
+    let validate = field_validate(field, name);
+    let parse = match field.attributes.settings.to_tokens() {
+        Some(settings) => quote_spanned! {span=>
+            ::twilight_interactions::command::CommandOption::from_option_with(__opt.value, __option_data, &#settings, __data.resolved.as_deref())
+        },
+        None => quote_spanned! {span=>
+            ::twilight_interactions::command::CommandOption::from_option(__opt.value, __option_data, __data.resolved.as_deref())
+        },
     };
 
     quote_spanned! {span=>
         #name => {
-            let __option_data = ::twilight_interactions::command::internal::CommandOptionData {
-                channel_types: #channel_types,
-                max_value: #max_value,
-                min_value: #min_value,
-                max_length: #max_length,
-                min_length: #min_length,
-            };
+            let __option_data = #option_data;
 
-            match ::twilight_interactions::command::CommandOption::from_option(__opt.value, __option_data, __data.resolved.as_deref()) {
-                ::std::result::Result::Ok(__value) => #ident = Some(__value),
+            match #parse {
+                ::std::result::Result::Ok(__value) => {
+                    #validate
+                    #target = Some(__value)
+                }
                 ::std::result::Result::Err(__kind) => {
                     return ::std::result::Result::Err(
                         ::twilight_interactions::error::ParseError::Option(
@@ -116,6 +295,43 @@ fn field_match_arm(field: &StructField) -> TokenStream {
     }
 }
 
+/// Generate field validation code
+///
+/// The validation function is only called with completed values for
+/// autocomplete fields.
+fn field_validate(field: &StructField, name: &str) -> TokenStream {
+    let Some(path) = &field.attributes.validate else {
+        return TokenStream::new();
+    };
+    let ty = &field.ty;
+
+    let validate = quote! {
+        if let ::std::result::Result::Err(__error) = #path(__inner) {
+            return ::std::result::Result::Err(
+                ::twilight_interactions::error::ParseError::Option(
+                    ::twilight_interactions::error::ParseOptionError {
+                        field: ::std::convert::From::from(#name),
+                        kind: ::twilight_interactions::error::ParseOptionErrorType::Custom(
+                            ::std::string::ToString::to_string(&__error)
+                        ),
+                })
+            )
+        }
+    };
+
+    match field.kind {
+        FieldType::Autocomplete => quote! {
+            if let ::twilight_interactions::command::AutocompleteValue::<#ty>::Completed(__inner) = &__value {
+                #validate
+            }
+        },
+        FieldType::Optional | FieldType::Required | FieldType::Repeated => quote! {
+            let __inner: &#ty = &__value;
+            #validate
+        },
+    }
+}
+
 /// Generate field constructor
 fn field_constructor(field: &StructField) -> TokenStream {
     let ident = &field.ident;
@@ -133,6 +349,32 @@ fn field_constructor(field: &StructField) -> TokenStream {
             }
         },
         FieldType::Optional => quote!(#ident),
+        FieldType::Repeated => {
+            let required = field
+                .option_names()
+                .into_iter()
+                .zip(field.options_required())
+                .enumerate()
+                .filter(|(_, (_, required))| *required)
+                .map(|(index, (name, _))| {
+                    quote! {
+                        if #ident[#index].is_none() {
+                            return Err(::twilight_interactions::error::ParseError::Option(
+                                ::twilight_interactions::error::ParseOptionError {
+                                    field: ::std::convert::From::from(#name),
+                                    kind: ::twilight_interactions::error::ParseOptionErrorType::RequiredField
+                            }))
+                        }
+                    }
+                });
+
+            quote! {
+                #ident: {
+                    #(#required)*
+                    #ident.into_iter().flatten().collect()
+                }
+            }
+        }
         FieldType::Autocomplete => quote! {
             #ident: match #ident {
                 Some(__value) => __value,
@@ -0,0 +1,86 @@
+//! Generation of autocomplete models (`autocomplete_model` attribute)
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{DeriveInput, Result};
+
+use super::{
+    command_model::model_impl,
+    parse::{FieldType, StructField},
+};
+use crate::parse::parsers::TypeName;
+
+/// Generate the autocomplete model of a command model
+///
+/// Fields with the `autocomplete` attribute are wrapped in `AutocompleteValue`,
+/// and other fields in `Option`. Option relationships are not checked. The
+/// model derives `Debug` like hand-written autocomplete models.
+pub fn autocomplete_model(
+    input: &DeriveInput,
+    name: TypeName,
+    fields: Vec<StructField>,
+) -> Result<TokenStream> {
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+
+    let fields = fields
+        .into_iter()
+        .map(autocomplete_field)
+        .collect::<Result<Vec<_>>>()?;
+
+    let ident = Ident::from(name);
+    let doc = format!("Autocomplete model of [`{}`].", input.ident);
+    let definitions = fields.iter().map(field_definition);
+    let model_impl = model_impl(&ident, generics, &fields, true, &[])?;
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug)]
+        #vis struct #ident #generics #where_clause {
+            #(#definitions),*
+        }
+
+        #model_impl
+    })
+}
+
+/// Convert a field of the command model to an autocomplete field
+fn autocomplete_field(mut field: StructField) -> Result<StructField> {
+    if let Some(repeat) = &field.attributes.repeat {
+        return Err(repeat.error("`repeat` fields are not supported with `autocomplete_model`"));
+    }
+
+    field.kind = match field.attributes.autocomplete {
+        true => FieldType::Autocomplete,
+        false => FieldType::Optional,
+    };
+    field.attributes.requires = None;
+    field.attributes.conflicts_with = None;
+    field.attributes.required_unless = None;
+
+    Ok(field)
+}
+
+/// Generate the definition of an autocomplete field
+fn field_definition(field: &StructField) -> TokenStream {
+    let vis = &field.vis;
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let docs = field
+        .raw_attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"));
+
+    let ty = match field.kind {
+        FieldType::Autocomplete => {
+            quote!(::twilight_interactions::command::AutocompleteValue<#ty>)
+        }
+        _ => quote!(::std::option::Option<#ty>),
+    };
+
+    quote_spanned! {field.span=>
+        #(#docs)*
+        #vis #ident: #ty
+    }
+}
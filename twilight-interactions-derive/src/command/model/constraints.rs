@@ -0,0 +1,254 @@
+//! Option relationships declared with the `requires`, `conflicts_with`,
+//! `required_unless` and `one_of` attributes.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Result};
+
+use super::parse::{FieldList, FieldType, StructField};
+
+/// Parsed option relationship
+pub enum Constraint<'a> {
+    Requires {
+        field: &'a StructField,
+        requires: &'a StructField,
+    },
+    ConflictsWith {
+        field: &'a StructField,
+        conflicts_with: &'a StructField,
+    },
+    RequiredUnless {
+        field: &'a StructField,
+        alternatives: Vec<&'a StructField>,
+    },
+    OneOf(Vec<&'a StructField>),
+}
+
+impl<'a> Constraint<'a> {
+    /// Collect constraints declared on fields and the `one_of` type attribute.
+    pub fn from_fields(fields: &'a [StructField], groups: &[FieldList]) -> Result<Vec<Self>> {
+        let mut constraints = Vec::new();
+
+        for field in fields {
+            if field.kind == FieldType::Repeated
+                && (field.attributes.requires.is_some()
+                    || field.attributes.conflicts_with.is_some())
+            {
+                return Err(Error::new(
+                    field.span,
+                    "option relationships are not supported on repeated fields",
+                ));
+            }
+
+            if let Some(list) = &field.attributes.requires {
+                for requires in find_fields(fields, list, field)? {
+                    constraints.push(Self::Requires { field, requires });
+                }
+            }
+
+            if let Some(list) = &field.attributes.conflicts_with {
+                for conflicts_with in find_fields(fields, list, field)? {
+                    constraints.push(Self::ConflictsWith {
+                        field,
+                        conflicts_with,
+                    });
+                }
+            }
+
+            if let Some(list) = &field.attributes.required_unless {
+                if field.kind != FieldType::Optional {
+                    return Err(Error::new(
+                        list.span,
+                        "`required_unless` can only be used on `Option` fields",
+                    ));
+                }
+
+                let alternatives = find_fields(fields, list, field)?;
+                constraints.push(Self::RequiredUnless {
+                    field,
+                    alternatives,
+                });
+            }
+        }
+
+        for group in groups {
+            let group_fields = group
+                .fields
+                .iter()
+                .map(|name| find_field(fields, name, group.span))
+                .collect::<Result<Vec<_>>>()?;
+
+            if group_fields.iter().any(|f| f.kind != FieldType::Optional) {
+                return Err(Error::new(
+                    group.span,
+                    "`one_of` can only reference `Option` fields",
+                ));
+            }
+
+            constraints.push(Self::OneOf(group_fields));
+        }
+
+        Ok(constraints)
+    }
+
+    /// Generate the code checking the constraint.
+    ///
+    /// The generated code expects field variables to be in scope as `Option`s.
+    pub fn check(&self) -> TokenStream {
+        match self {
+            Self::Requires { field, requires } => {
+                let (ident, name) = (&field.ident, option_name(field));
+                let (requires_ident, requires_name) = (&requires.ident, option_name(requires));
+
+                quote! {
+                    if #ident.is_some() && #requires_ident.is_none() {
+                        return ::std::result::Result::Err(
+                            ::twilight_interactions::error::ParseError::MissingRequirement {
+                                field: ::std::convert::From::from(#name),
+                                requires: ::std::convert::From::from(#requires_name),
+                            }
+                        );
+                    }
+                }
+            }
+            Self::ConflictsWith {
+                field,
+                conflicts_with,
+            } => {
+                let (ident, name) = (&field.ident, option_name(field));
+                let (other_ident, other_name) =
+                    (&conflicts_with.ident, option_name(conflicts_with));
+
+                quote! {
+                    if #ident.is_some() && #other_ident.is_some() {
+                        return ::std::result::Result::Err(
+                            ::twilight_interactions::error::ParseError::Conflict {
+                                field: ::std::convert::From::from(#name),
+                                conflicts_with: ::std::convert::From::from(#other_name),
+                            }
+                        );
+                    }
+                }
+            }
+            Self::RequiredUnless {
+                field,
+                alternatives,
+            } => {
+                let group: Vec<_> = std::iter::once(*field)
+                    .chain(alternatives.iter().copied())
+                    .collect();
+
+                missing_group(&group)
+            }
+            Self::OneOf(group) => missing_group(group),
+        }
+    }
+
+    /// Generate the [`OptionConstraint`] metadata of the constraint.
+    ///
+    /// [`OptionConstraint`]: twilight_interactions::command::OptionConstraint
+    pub fn metadata(&self) -> TokenStream {
+        match self {
+            Self::Requires { field, requires } => {
+                let (name, requires) = (option_name(field), option_name(requires));
+
+                quote! {
+                    ::twilight_interactions::command::OptionConstraint::Requires {
+                        field: #name,
+                        requires: #requires,
+                    }
+                }
+            }
+            Self::ConflictsWith {
+                field,
+                conflicts_with,
+            } => {
+                let (name, conflicts_with) = (option_name(field), option_name(conflicts_with));
+
+                quote! {
+                    ::twilight_interactions::command::OptionConstraint::ConflictsWith {
+                        field: #name,
+                        conflicts_with: #conflicts_with,
+                    }
+                }
+            }
+            Self::RequiredUnless {
+                field,
+                alternatives,
+            } => {
+                let name = option_name(field);
+                let alternatives = alternatives.iter().map(|f| option_name(f));
+
+                quote! {
+                    ::twilight_interactions::command::OptionConstraint::RequiredUnless {
+                        field: #name,
+                        alternatives: &[#(#alternatives),*],
+                    }
+                }
+            }
+            Self::OneOf(group) => {
+                let names = group.iter().map(|f| option_name(f));
+
+                quote! {
+                    ::twilight_interactions::command::OptionConstraint::OneOf(&[#(#names),*])
+                }
+            }
+        }
+    }
+}
+
+/// Generate the code checking that at least one field of the group is present
+fn missing_group(group: &[&StructField]) -> TokenStream {
+    let idents = group.iter().map(|f| &f.ident);
+    let names = group.iter().map(|f| option_name(f));
+
+    quote! {
+        if #(#idents.is_none())&&* {
+            return ::std::result::Result::Err(
+                ::twilight_interactions::error::ParseError::MissingOneOf(
+                    ::std::vec![#(::std::convert::From::from(#names)),*]
+                )
+            );
+        }
+    }
+}
+
+/// Find the fields referenced by a [`FieldList`]
+fn find_fields<'a>(
+    fields: &'a [StructField],
+    list: &FieldList,
+    current: &StructField,
+) -> Result<Vec<&'a StructField>> {
+    list.fields
+        .iter()
+        .map(|name| {
+            if current.ident == name {
+                return Err(Error::new(list.span, "field cannot reference itself"));
+            }
+
+            find_field(fields, name, list.span)
+        })
+        .collect()
+}
+
+/// Find a field by its identifier
+fn find_field<'a>(fields: &'a [StructField], name: &str, span: Span) -> Result<&'a StructField> {
+    let field = fields
+        .iter()
+        .find(|field| field.ident == name)
+        .ok_or_else(|| Error::new(span, format!("unknown field `{name}`")))?;
+
+    if field.kind == FieldType::Repeated {
+        return Err(Error::new(
+            span,
+            "option relationships are not supported on repeated fields",
+        ));
+    }
+
+    Ok(field)
+}
+
+/// Get the option name of a field
+fn option_name(field: &StructField) -> String {
+    field.attributes.name_default(field.ident.to_string())
+}
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, DeriveInput, Error, FieldsNamed, Result};
 
-use super::parse::{channel_type, command_option_value, StructField, TypeAttribute};
+use super::parse::{StructField, TypeAttribute};
 use crate::{
     command::user_application::{context, integration_type},
     localization::{description_expr, name_expr},
@@ -21,7 +21,7 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
 
     check_fields_order(&fields)?;
 
-    let capacity = fields.len();
+    let capacity: usize = fields.iter().map(|field| field.option_names().len()).sum();
     let (attributes, attr_span) = match find_attr(&input.attrs, "command") {
         Some(attr) => (TypeAttribute::parse(attr)?, attr.span()),
         None => {
@@ -106,32 +106,60 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
 }
 
 /// Generate field option code
-fn field_option(field: &StructField) -> Result<TokenStream> {
+pub fn field_option(field: &StructField) -> Result<TokenStream> {
     let ty = &field.ty;
     let span = field.span;
 
     let name = field.attributes.name_default(field.ident.to_string());
     let name_expr = name_expr(&name, &field.attributes.name_localizations);
 
-    let desc_expr = description_expr(
-        &field.attributes.desc,
-        &field.attributes.desc_localizations,
-        || parse_doc(&field.raw_attrs, span),
-    )?;
+    // Fields without description use the default description of their type
+    let desc_expr = if field.attributes.desc.is_none()
+        && field.attributes.desc_localizations.is_none()
+        && find_attr(&field.raw_attrs, "doc").is_none()
+    {
+        default_description(field, &name)
+    } else {
+        description_expr(
+            &field.attributes.desc,
+            &field.attributes.desc_localizations,
+            || parse_doc(&field.raw_attrs, span),
+        )?
+    };
 
-    let required = field.kind.required();
     let autocomplete = field.attributes.autocomplete;
-    let max_value = command_option_value(field.attributes.max_value);
-    let min_value = command_option_value(field.attributes.min_value);
-    let max_length = optional(field.attributes.max_length);
-    let min_length = optional(field.attributes.min_length);
+    let option_data = field
+        .attributes
+        .data
+        .to_tokens(quote!(<#ty as ::twilight_interactions::command::CreateOption>::default_data()));
+
+    // Repeated fields are registered as numbered options, the first
+    // `min_items` being required.
+    if let Some(repeat) = &field.attributes.repeat {
+        let count = repeat.inner;
+        let min = field.attributes.settings.min_items.unwrap_or(0);
+
+        return Ok(quote_spanned! {span =>
+            for __index in 1..=#count {
+                let __field_desc = #desc_expr;
+                let __field_name = (#name_expr).numbered(__index);
+
+                __command_options.push(<#ty as ::twilight_interactions::command::CreateOption>::create_option(
+                    ::twilight_interactions::command::internal::CreateOptionData {
+                        name: __field_name.fallback,
+                        name_localizations: __field_name.localizations,
+                        description: __field_desc.fallback,
+                        description_localizations: __field_desc.localizations,
+                        required: ::std::option::Option::Some(__index <= #min),
+                        autocomplete: #autocomplete,
+                        data: #option_data,
+                    }
+                ));
+            }
+        });
+    }
 
-    let channel_types = if field.attributes.channel_types.is_empty() {
-        quote! { ::std::option::Option::None }
-    } else {
-        let items = field.attributes.channel_types.iter().map(channel_type);
-        quote! { ::std::option::Option::Some(::std::vec![#(#items),*]) }
-    };
+    let required = field.kind.required();
 
     Ok(quote_spanned! {span => {
         let __field_desc = #desc_expr;
@@ -145,32 +173,48 @@ fn field_option(field: &StructField) -> Result<TokenStream> {
                 description_localizations: __field_desc.localizations,
                 required: ::std::option::Option::Some(#required),
                 autocomplete: #autocomplete,
-                data: ::twilight_interactions::command::internal::CommandOptionData {
-                    channel_types: #channel_types,
-                    max_value: #max_value,
-                    min_value: #min_value,
-                    max_length: #max_length,
-                    min_length: #min_length,
-                },
+                data: #option_data,
             }
         ));
     }})
 }
 
+/// Generate the description of a field without description
+///
+/// The default description of the option type is used, and the generated code
+/// panics if the type has none.
+fn default_description(field: &StructField, name: &str) -> TokenStream {
+    let ty = &field.ty;
+
+    quote_spanned! {field.span=>
+        match <#ty as ::twilight_interactions::command::CreateOption>::default_description() {
+            ::std::option::Option::Some(__desc) => {
+                ::twilight_interactions::command::internal::IntoLocalizationsInternal::into_localizations(__desc)
+            }
+            ::std::option::Option::None => ::std::panic!(
+                "option `{}` has no description (documentation comment or `desc` attribute) and its type has no default description",
+                #name
+            ),
+        }
+    }
+}
+
 /// Ensure optional options are after required ones
-fn check_fields_order(fields: &[StructField]) -> Result<()> {
+pub fn check_fields_order(fields: &[StructField]) -> Result<()> {
     let mut optional_option_added = false;
 
     for field in fields {
-        if !optional_option_added && !field.kind.required() {
-            optional_option_added = true;
-        }
+        for required in field.options_required() {
+            if !optional_option_added && !required {
+                optional_option_added = true;
+            }
 
-        if optional_option_added && field.kind.required() {
-            return Err(Error::new(
-                field.span,
-                "required options should be added before optional",
-            ));
+            if optional_option_added && required {
+                return Err(Error::new(
+                    field.span,
+                    "required options should be added before optional",
+                ));
+            }
         }
     }
 
@@ -2,8 +2,11 @@
 //! named fields.
 
 mod command_model;
+mod companion;
+mod constraints;
 mod create_command;
 mod parse;
 
-pub use command_model::impl_command_model;
-pub use create_command::impl_create_command;
+pub use command_model::{impl_command_model, model_body};
+pub use create_command::{check_fields_order, field_option, impl_create_command};
+pub use parse::StructField;
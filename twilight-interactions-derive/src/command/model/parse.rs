@@ -1,14 +1,14 @@
 //! Parsing of struct fields and attributes
 
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{spanned::Spanned, Attribute, Error, Lit, Result, Type};
+use proc_macro2::{Ident, Span};
+use syn::{spanned::Spanned, Attribute, Error, Lit, Result, Type, Visibility};
 
 use crate::{
     command::user_application::{ApplicationIntegrationType, InteractionContextType},
     parse::{
         attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
-        parsers::{CommandDescription, CommandName, FunctionPath},
+        option_data::{OptionData, OptionSettings},
+        parsers::{CommandDescription, CommandName, FunctionPath, TypeName},
         syntax::{extract_generic, find_attr},
     },
 };
@@ -16,6 +16,7 @@ use crate::{
 /// Parsed struct field
 pub struct StructField {
     pub span: Span,
+    pub vis: Visibility,
     pub ident: Ident,
     pub ty: Type,
     pub raw_attrs: Vec<Attribute>,
@@ -29,6 +30,7 @@ pub enum FieldType {
     Autocomplete,
     Optional,
     Required,
+    Repeated,
 }
 
 impl StructField {
@@ -55,6 +57,11 @@ impl StructField {
             None => FieldAttribute::default(),
         };
 
+        let (kind, ty) = match &attributes.repeat {
+            Some(repeat) => Self::repeated(kind, ty, repeat, &attributes)?,
+            None => (kind, ty),
+        };
+
         let Some(ident) = field.ident else {
             return Err(Error::new_spanned(
                 field,
@@ -64,6 +71,7 @@ impl StructField {
 
         Ok(Self {
             span: field.ty.span(),
+            vis: field.vis,
             ident,
             ty,
             raw_attrs: field.attrs,
@@ -72,6 +80,56 @@ impl StructField {
         })
     }
 
+    /// Check a field with the `repeat` attribute and extract the item type
+    fn repeated(
+        kind: FieldType,
+        ty: Type,
+        repeat: &ParseSpanned<u16>,
+        attributes: &FieldAttribute,
+    ) -> Result<(FieldType, Type)> {
+        let item = match extract_generic(&ty, "Vec") {
+            Some(item) if kind == FieldType::Required => item,
+            _ => return Err(repeat.error("`repeat` can only be used on `Vec<T>` fields")),
+        };
+
+        if !(1..=25).contains(&repeat.inner) {
+            return Err(repeat.error("`repeat` must be between 1 and 25"));
+        }
+
+        if attributes
+            .settings
+            .min_items
+            .is_some_and(|min| min > repeat.inner)
+        {
+            return Err(repeat.error("`min_items` cannot be greater than `repeat`"));
+        }
+
+        Ok((FieldType::Repeated, item))
+    }
+
+    /// Names of the options of the field
+    ///
+    /// Repeated fields have one option per item, numbered from 1.
+    pub fn option_names(&self) -> Vec<String> {
+        let name = self.attributes.name_default(self.ident.to_string());
+
+        match &self.attributes.repeat {
+            Some(repeat) => (1..=repeat.inner).map(|i| format!("{name}{i}")).collect(),
+            None => vec![name],
+        }
+    }
+
+    /// Whether each option of the field is required
+    pub fn options_required(&self) -> Vec<bool> {
+        match &self.attributes.repeat {
+            Some(repeat) => {
+                let min = self.attributes.settings.min_items.unwrap_or(0);
+                (1..=repeat.inner).map(|i| i <= min).collect()
+            }
+            None => vec![self.kind.required()],
+        }
+    }
+
     /// Parse [`syn::FieldsNamed`] as a [`Vec<StructField>`]
     pub fn from_fields(fields: syn::FieldsNamed) -> Result<Vec<Self>> {
         fields.named.into_iter().map(Self::from_field).collect()
@@ -82,7 +140,7 @@ impl FieldType {
     pub fn required(&self) -> bool {
         match self {
             Self::Required => true,
-            Self::Autocomplete | Self::Optional => false,
+            Self::Autocomplete | Self::Optional | Self::Repeated => false,
         }
     }
 }
@@ -91,6 +149,8 @@ impl FieldType {
 pub struct TypeAttribute {
     /// Whether the model is an autocomplete interaction model.
     pub autocomplete: Option<bool>,
+    /// Name of the generated autocomplete model.
+    pub autocomplete_model: Option<TypeName>,
     /// Command name.
     pub name: Option<CommandName>,
     /// Localization dictionary for the command name.
@@ -109,11 +169,14 @@ pub struct TypeAttribute {
     pub contexts: Option<Vec<InteractionContextType>>,
     /// Installation contexts where the command is available.
     pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Groups of fields where at least one field is required.
+    pub one_of: Vec<FieldList>,
 }
 
 impl TypeAttribute {
     const VALID_ATTRIBUTES: &'static [&'static str] = &[
         "autocomplete",
+        "autocomplete_model",
         "name",
         "name_localizations",
         "desc",
@@ -123,6 +186,7 @@ impl TypeAttribute {
         "nsfw",
         "contexts",
         "integration_types",
+        "one_of",
     ];
 
     pub fn parse(attr: &Attribute) -> Result<Self> {
@@ -130,6 +194,7 @@ impl TypeAttribute {
 
         Ok(Self {
             autocomplete: parser.optional("autocomplete")?,
+            autocomplete_model: parser.optional("autocomplete_model")?,
             name: parser.optional("name")?,
             name_localizations: parser.optional("name_localizations")?,
             desc: parser.optional("desc")?,
@@ -139,6 +204,7 @@ impl TypeAttribute {
             nsfw: parser.optional("nsfw")?,
             contexts: parser.optional("contexts")?,
             integration_types: parser.optional("integration_types")?,
+            one_of: parser.all("one_of")?,
         })
     }
 }
@@ -156,16 +222,20 @@ pub struct FieldAttribute {
     pub desc_localizations: Option<FunctionPath>,
     /// Whether the field supports autocomplete
     pub autocomplete: bool,
-    /// Limit to specific channel types
-    pub channel_types: Vec<ChannelType>,
-    /// Maximum value permitted
-    pub max_value: Option<CommandOptionValue>,
-    /// Minimum value permitted
-    pub min_value: Option<CommandOptionValue>,
-    /// Maximum string length
-    pub max_length: Option<u16>,
-    /// Minimum string length
-    pub min_length: Option<u16>,
+    /// Data of the command option
+    pub data: OptionData,
+    /// Settings used when parsing the option
+    pub settings: OptionSettings,
+    /// Number of repeated options
+    pub repeat: Option<ParseSpanned<u16>>,
+    /// Function used to validate the parsed value
+    pub validate: Option<FunctionPath>,
+    /// Fields required by this field
+    pub requires: Option<FieldList>,
+    /// Fields conflicting with this field
+    pub conflicts_with: Option<FieldList>,
+    /// Fields that make this field optional
+    pub required_unless: Option<FieldList>,
 }
 
 impl FieldAttribute {
@@ -175,16 +245,22 @@ impl FieldAttribute {
         "desc",
         "desc_localizations",
         "autocomplete",
-        "channel_types",
-        "max_value",
-        "min_value",
-        "max_length",
-        "min_length",
+        "repeat",
+        "validate",
+        "requires",
+        "conflicts_with",
+        "required_unless",
     ];
 
     /// Parse a single [`Attribute`]
     pub fn parse(attr: &Attribute) -> Result<Self> {
-        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+        let valid = [
+            Self::VALID_ATTRIBUTES,
+            OptionData::VALID_ATTRIBUTES,
+            OptionSettings::VALID_ATTRIBUTES,
+        ]
+        .concat();
+        let mut parser = NamedAttrs::parse(attr, &valid)?;
 
         Ok(Self {
             rename: parser.optional("rename")?,
@@ -192,11 +268,13 @@ impl FieldAttribute {
             desc: parser.optional("desc")?,
             desc_localizations: parser.optional("desc_localizations")?,
             autocomplete: parser.optional("autocomplete")?.unwrap_or_default(),
-            channel_types: parser.optional("channel_types")?.unwrap_or_default(),
-            max_value: parser.optional("max_value")?,
-            min_value: parser.optional("min_value")?,
-            max_length: parser.optional("max_length")?,
-            min_length: parser.optional("min_length")?,
+            data: OptionData::parse(&mut parser)?,
+            settings: OptionSettings::parse(&mut parser)?,
+            repeat: parser.optional("repeat")?,
+            validate: parser.optional("validate")?,
+            requires: parser.optional("requires")?,
+            conflicts_with: parser.optional("conflicts_with")?,
+            required_unless: parser.optional("required_unless")?,
         })
     }
 
@@ -208,122 +286,28 @@ impl FieldAttribute {
     }
 }
 
-/// Parsed channel type
-pub enum ChannelType {
-    GuildText,
-    Private,
-    GuildVoice,
-    Group,
-    GuildCategory,
-    GuildAnnouncement,
-    GuildStore,
-    AnnouncementThread,
-    PublicThread,
-    PrivateThread,
-    GuildStageVoice,
-    GuildDirectory,
-    GuildForum,
-    GuildMedia,
+/// Parsed list of field identifiers
+pub struct FieldList {
+    pub span: Span,
+    pub fields: Vec<String>,
 }
 
-impl ParseAttribute for Vec<ChannelType> {
+impl ParseAttribute for FieldList {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
-
-        spanned
+        let fields: Vec<_> = spanned
             .inner
             .split_ascii_whitespace()
-            .map(|value| ChannelType::parse(value, spanned.span))
-            .collect()
-    }
-}
+            .map(String::from)
+            .collect();
 
-impl ChannelType {
-    fn parse(value: &str, span: Span) -> Result<Self> {
-        match value {
-            "guild_text" => Ok(Self::GuildText),
-            "private" => Ok(Self::Private),
-            "guild_voice" => Ok(Self::GuildVoice),
-            "group" => Ok(Self::Group),
-            "guild_category" => Ok(Self::GuildCategory),
-            "guild_announcement" | "guild_news" => Ok(Self::GuildAnnouncement),
-            "guild_store" => Ok(Self::GuildStore),
-            "announcement_thread" | "guild_news_thread" => Ok(Self::AnnouncementThread),
-            "public_thread" | "guild_public_thread" => Ok(Self::PublicThread),
-            "private_thread" | "guild_private_thread" => Ok(Self::PrivateThread),
-            "guild_stage_voice" => Ok(Self::GuildStageVoice),
-            "guild_directory" => Ok(Self::GuildDirectory),
-            "guild_forum" => Ok(Self::GuildForum),
-            "guild_media" => Ok(Self::GuildMedia),
-            invalid => Err(Error::new(
-                span,
-                format!("`{invalid}` is not a valid channel type"),
-            )),
+        if fields.is_empty() {
+            return Err(spanned.error("expected at least one field"));
         }
-    }
-}
-
-/// Parsed command option value
-#[derive(Clone, Copy)]
-pub enum CommandOptionValue {
-    Integer(i64),
-    Number(f64),
-}
-
-impl ParseAttribute for CommandOptionValue {
-    fn parse_attribute(input: Lit) -> Result<Self> {
-        match input {
-            Lit::Int(inner) => Ok(Self::Integer(inner.base10_parse()?)),
-            Lit::Float(inner) => Ok(Self::Number(inner.base10_parse()?)),
-            _ => Err(Error::new_spanned(
-                input,
-                "expected integer or floating point literal",
-            )),
-        }
-    }
-}
-
-/// Convert a [`ChannelType`] into a [`TokenStream`]
-pub fn channel_type(kind: &ChannelType) -> TokenStream {
-    match kind {
-        ChannelType::GuildText => quote!(::twilight_model::channel::ChannelType::GuildText),
-        ChannelType::Private => quote!(::twilight_model::channel::ChannelType::Private),
-        ChannelType::GuildVoice => quote!(::twilight_model::channel::ChannelType::GuildVoice),
-        ChannelType::Group => quote!(::twilight_model::channel::ChannelType::Group),
-        ChannelType::GuildCategory => quote!(::twilight_model::channel::ChannelType::GuildCategory),
-        ChannelType::GuildAnnouncement => {
-            quote!(::twilight_model::channel::ChannelType::GuildAnnouncement)
-        }
-        ChannelType::GuildStore => quote!(::twilight_model::channel::ChannelType::GuildStore),
-        ChannelType::AnnouncementThread => {
-            quote!(::twilight_model::channel::ChannelType::AnnouncementThread)
-        }
-        ChannelType::PublicThread => {
-            quote!(::twilight_model::channel::ChannelType::PublicThread)
-        }
-        ChannelType::PrivateThread => {
-            quote!(::twilight_model::channel::ChannelType::PrivateThread)
-        }
-        ChannelType::GuildStageVoice => {
-            quote!(::twilight_model::channel::ChannelType::GuildStageVoice)
-        }
-        ChannelType::GuildDirectory => {
-            quote!(::twilight_model::channel::ChannelType::GuildDirectory)
-        }
-        ChannelType::GuildForum => quote!(::twilight_model::channel::ChannelType::GuildForum),
-        ChannelType::GuildMedia => quote!(::twilight_model::channel::ChannelType::GuildMedia),
-    }
-}
 
-/// Convert a [`Option<CommandOptionValue>`] into a [`TokenStream`]
-pub fn command_option_value(value: Option<CommandOptionValue>) -> TokenStream {
-    match value {
-        None => quote!(::std::option::Option::None),
-        Some(CommandOptionValue::Integer(inner)) => {
-            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Integer(#inner)))
-        }
-        Some(CommandOptionValue::Number(inner)) => {
-            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Number(#inner)))
-        }
+        Ok(Self {
+            span: spanned.span,
+            fields,
+        })
     }
 }
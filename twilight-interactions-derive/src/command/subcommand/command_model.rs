@@ -1,8 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, DeriveInput, Result, Variant};
+use syn::{spanned::Spanned, DeriveInput, Generics, Result, Variant};
 
-use super::parse::ParsedVariant;
+use super::parse::{ParsedVariant, TypeAttribute, VariantKind};
+use crate::{command::model::model_body, parse::syntax::find_attr};
 
 /// Implementation of `CommandModel` derive macro
 pub fn impl_command_model(
@@ -14,12 +15,27 @@ pub fn impl_command_model(
     let where_clause = &generics.where_clause;
     let variants = ParsedVariant::from_variants(variants, input.span())?;
 
-    let variants_match_arms = variants.iter().map(variant_match_arm);
+    let autocomplete = match find_attr(&input.attrs, "command") {
+        Some(attr) => TypeAttribute::parse(attr)?.autocomplete.unwrap_or(false),
+        None => false,
+    };
+
+    let variants_match_arms = variants
+        .iter()
+        .map(|variant| variant_match_arm(variant, generics, autocomplete))
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(quote! {
         impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
             fn from_interaction(
                 __data: ::twilight_interactions::command::CommandInputData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                Self::from_interaction_with(__data, &::std::default::Default::default())
+            }
+
+            fn from_interaction_with(
+                __data: ::twilight_interactions::command::CommandInputData,
+                __context: &::twilight_interactions::command::CommandContext,
             ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
                 if __data.options.is_empty() {
                     return std::result::Result::Err(twilight_interactions::error::ParseError::EmptyOptions);
@@ -45,12 +61,40 @@ pub fn impl_command_model(
 }
 
 /// Generate variant match arm
-fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
+fn variant_match_arm(
+    variant: &ParsedVariant,
+    generics: &Generics,
+    autocomplete: bool,
+) -> Result<TokenStream> {
     let name = &variant.attribute.name;
     let ident = &variant.ident;
     let span = variant.span;
 
-    quote_spanned! {span=>
+    // Inline variants are parsed like a command model
+    let parse_variant = match &variant.kind {
+        VariantKind::Wrapped(_) => quote! {
+            Ok(Self::#ident(
+                ::twilight_interactions::command::CommandModel::from_interaction_with(__input, __context)?
+            ))
+        },
+        VariantKind::Inline(fields) => {
+            let (body, _) = model_body(
+                fields,
+                autocomplete,
+                &[],
+                generics,
+                span,
+                quote!(Self::#ident),
+            )?;
+
+            quote! {
+                let __data = __input;
+                #body
+            }
+        }
+    };
+
+    Ok(quote_spanned! {span=>
         #name => {
             let __input = match ::twilight_interactions::command::CommandInputData::from_option(__opt.value, __data.resolved.as_deref()) {
                 Ok(__value) => __value,
@@ -64,9 +108,7 @@ fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
                 )
             };
 
-            Ok(Self::#ident(
-                ::twilight_interactions::command::CommandModel::from_interaction(__input)?
-            ))
+            #parse_variant
         }
-    }
+    })
 }
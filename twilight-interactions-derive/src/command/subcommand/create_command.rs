@@ -1,10 +1,13 @@
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, DeriveInput, Error, Result, Variant};
+use syn::{spanned::Spanned, DeriveInput, Error, Result, TypePath, Variant};
 
-use super::parse::{ParsedVariant, TypeAttribute};
+use super::parse::{ParsedVariant, TypeAttribute, VariantKind};
 use crate::{
-    command::user_application::{context, integration_type},
+    command::{
+        model::{check_fields_order, field_option, StructField},
+        user_application::{context, integration_type},
+    },
     localization::{description_expr, name_expr},
     parse::syntax::{find_attr, optional, parse_doc},
 };
@@ -19,8 +22,8 @@ pub fn impl_create_command(
     let where_clause = &generics.where_clause;
 
     let variants = ParsedVariant::from_variants(variants, input.span())?;
-    let attributes = match find_attr(&input.attrs, "command") {
-        Some(attr) => TypeAttribute::parse(attr)?,
+    let (attributes, attr_span) = match find_attr(&input.attrs, "command") {
+        Some(attr) => (TypeAttribute::parse(attr)?, attr.span()),
         None => {
             return Err(Error::new_spanned(
                 input,
@@ -29,7 +32,17 @@ pub fn impl_create_command(
         }
     };
 
-    let name = String::from(attributes.name);
+    if attributes.autocomplete == Some(true) {
+        return Err(Error::new(
+            attr_span,
+            "cannot implement `CreateCommand` on partial model",
+        ));
+    }
+
+    let name = match attributes.name {
+        Some(name) => String::from(name),
+        None => return Err(Error::new(attr_span, "missing required attribute `name`")),
+    };
     let name_expr = name_expr(&name, &attributes.name_localizations);
 
     let desc_expr = description_expr(&attributes.desc, &attributes.desc_localizations, || {
@@ -44,7 +57,12 @@ pub fn impl_create_command(
     let dm_permission = optional(attributes.dm_permission);
     let nsfw = optional(attributes.nsfw);
 
-    let variant_options = variants.iter().map(variant_option);
+    let nesting_depth = nesting_depth(&variants);
+    let nesting_checks = variants.iter().filter_map(nesting_check);
+    let variant_options = variants
+        .iter()
+        .map(variant_option)
+        .collect::<Result<Vec<_>>>()?;
 
     let contexts = if let Some(items) = attributes.contexts {
         let items = items.iter().map(context);
@@ -63,11 +81,12 @@ pub fn impl_create_command(
     Ok(quote! {
         impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
             const NAME: &'static str = #name;
+            const NESTING_DEPTH: usize = #nesting_depth;
 
             fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
                 let __command_name = #name_expr;
                 let __command_desc = #desc_expr;
-                let mut __command_options = ::std::vec::Vec::with_capacity(#capacity);
+                let mut __subcommands = ::std::vec::Vec::with_capacity(#capacity);
 
                 #(#variant_options)*
 
@@ -76,7 +95,7 @@ pub fn impl_create_command(
                     name_localizations: __command_name.localizations,
                     description: __command_desc.fallback,
                     description_localizations: __command_desc.localizations,
-                    options: __command_options,
+                    options: __subcommands,
                     default_member_permissions: #default_permissions,
                     dm_permission: #dm_permission,
                     nsfw: #nsfw,
@@ -86,17 +105,149 @@ pub fn impl_create_command(
                 }
             }
         }
+
+        const _: () = {
+            #(#nesting_checks)*
+        };
     })
 }
 
+/// Generate the compile-time check of the nesting depth of a wrapped variant
+///
+/// Subcommand groups cannot be nested in other groups. The check is done in a
+/// `const` item rather than in `create_command`, so that it is evaluated by
+/// `cargo check` and not only when the function is monomorphized.
+fn nesting_check(variant: &ParsedVariant) -> Option<TokenStream> {
+    match &variant.kind {
+        VariantKind::Wrapped(ty) => Some(quote_spanned! {variant.span=>
+            ::twilight_interactions::command::internal::check_nesting_depth(
+                <#ty as ::twilight_interactions::command::CreateCommand>::NESTING_DEPTH
+            );
+        }),
+        VariantKind::Inline(_) => None,
+    }
+}
+
 /// Generate variant option code
-fn variant_option(variant: &ParsedVariant) -> TokenStream {
-    let ty = &variant.inner;
+fn variant_option(variant: &ParsedVariant) -> Result<TokenStream> {
+    match &variant.kind {
+        VariantKind::Wrapped(ty) => wrapped_variant_option(variant, ty),
+        VariantKind::Inline(fields) => inline_variant_option(variant, fields),
+    }
+}
+
+/// Generate option code of a variant wrapping a command
+///
+/// The name and description of the variant override the ones of the inner
+/// command.
+fn wrapped_variant_option(variant: &ParsedVariant, ty: &TypePath) -> Result<TokenStream> {
     let span = variant.span;
+    let attribute = &variant.attribute;
+
+    let name = String::from(attribute.name.clone());
+    let name_expr = name_expr(&name, &attribute.name_localizations);
+    // Localized names of the inner command are only kept with the same name
+    let name_localizations = match attribute.name_localizations {
+        Some(_) => quote!(__subcommand.name_localizations = __subcommand_name.localizations;),
+        None => quote! {
+            if __subcommand.name != __subcommand_name.fallback {
+                __subcommand.name_localizations = ::std::option::Option::None;
+            }
+        },
+    };
 
-    quote_spanned! {span=>
-        __command_options.push(::std::convert::From::from(
-            <#ty as ::twilight_interactions::command::CreateCommand>::create_command()
+    let desc = match (
+        &attribute.desc,
+        &attribute.desc_localizations,
+        find_attr(&variant.raw_attrs, "doc"),
+    ) {
+        (None, None, None) => TokenStream::new(),
+        (desc, localizations, _) => {
+            let desc_expr =
+                description_expr(desc, localizations, || parse_doc(&variant.raw_attrs, span))?;
+
+            quote! {
+                let __subcommand_desc = #desc_expr;
+                __subcommand.description = __subcommand_desc.fallback;
+                __subcommand.description_localizations = __subcommand_desc.localizations;
+            }
+        }
+    };
+
+    Ok(quote_spanned! {span=> {
+        let mut __subcommand = <#ty as ::twilight_interactions::command::CreateCommand>::create_command();
+        let __subcommand_name = #name_expr;
+        #name_localizations
+        __subcommand.name = __subcommand_name.fallback;
+        #desc
+
+        __subcommands.push(::std::convert::From::from(__subcommand));
+    }})
+}
+
+/// Generate option code of an inline variant
+///
+/// Inline variants are created like a command model.
+fn inline_variant_option(variant: &ParsedVariant, fields: &[StructField]) -> Result<TokenStream> {
+    let span = variant.span;
+    check_fields_order(fields)?;
+
+    let name = String::from(variant.attribute.name.clone());
+    let name_expr = name_expr(&name, &variant.attribute.name_localizations);
+    let desc_expr = description_expr(
+        &variant.attribute.desc,
+        &variant.attribute.desc_localizations,
+        || parse_doc(&variant.raw_attrs, span),
+    )?;
+
+    let capacity: usize = fields.iter().map(|field| field.option_names().len()).sum();
+    let field_options = fields
+        .iter()
+        .map(field_option)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! { {
+        let mut __command_options = ::std::vec::Vec::with_capacity(#capacity);
+
+        #(#field_options)*
+
+        let __command_name = #name_expr;
+        let __command_desc = #desc_expr;
+
+        __subcommands.push(::std::convert::From::from(
+            ::twilight_interactions::command::ApplicationCommandData {
+                name: __command_name.fallback,
+                name_localizations: __command_name.localizations,
+                description: __command_desc.fallback,
+                description_localizations: __command_desc.localizations,
+                options: __command_options,
+                default_member_permissions: ::std::option::Option::None,
+                dm_permission: ::std::option::Option::None,
+                nsfw: ::std::option::Option::None,
+                group: false,
+                contexts: ::std::option::Option::None,
+                integration_types: ::std::option::Option::None,
+            }
         ));
-    }
+    }})
+}
+
+/// Generate the nesting depth of the command
+///
+/// Inline variants are subcommands and do not add nesting levels.
+fn nesting_depth(variants: &[ParsedVariant]) -> TokenStream {
+    let inner_depths = variants.iter().filter_map(|variant| match &variant.kind {
+        VariantKind::Wrapped(ty) => Some(quote! {
+            if <#ty as ::twilight_interactions::command::CreateCommand>::NESTING_DEPTH > __depth {
+                __depth = <#ty as ::twilight_interactions::command::CreateCommand>::NESTING_DEPTH;
+            }
+        }),
+        VariantKind::Inline(_) => None,
+    });
+
+    quote! { {
+        let mut __depth = 0;
+        #(#inner_depths)*
+        __depth + 1
+    } }
 }
@@ -1,8 +1,13 @@
 use proc_macro2::{Ident, Span};
-use syn::{spanned::Spanned, Attribute, Error, Fields, Result, Type, TypePath, Variant};
+use syn::{
+    spanned::Spanned, Attribute, Error, Fields, FieldsUnnamed, Result, Type, TypePath, Variant,
+};
 
 use crate::{
-    command::user_application::{ApplicationIntegrationType, InteractionContextType},
+    command::{
+        model::StructField,
+        user_application::{ApplicationIntegrationType, InteractionContextType},
+    },
     parse::{
         attribute::NamedAttrs,
         parsers::{CommandDescription, CommandName, FunctionPath},
@@ -14,8 +19,17 @@ use crate::{
 pub struct ParsedVariant {
     pub span: Span,
     pub ident: Ident,
+    pub raw_attrs: Vec<Attribute>,
     pub attribute: VariantAttribute,
-    pub inner: TypePath,
+    pub kind: VariantKind,
+}
+
+/// Kind of a parsed enum variant
+pub enum VariantKind {
+    /// Tuple variant wrapping a type implementing the command traits
+    Wrapped(TypePath),
+    /// Variant with named fields or unit variant, parsed as a subcommand
+    Inline(Vec<StructField>),
 }
 
 impl ParsedVariant {
@@ -39,26 +53,10 @@ impl ParsedVariant {
     /// Parse a single syn [`Variant`].
     fn from_variant(variant: Variant) -> Result<Self> {
         let span = variant.span();
-        let Fields::Unnamed(fields) = variant.fields else {
-            return Err(Error::new(span, "variant must be an unnamed variant"));
-        };
-
-        if fields.unnamed.len() != 1 {
-            return Err(Error::new(
-                span,
-                "variant must have exactly one unnamed field",
-            ));
-        }
-
-        let inner = match &fields.unnamed[0].ty {
-            // Safety: len is checked above
-            Type::Path(ty) => ty.clone(),
-            other => {
-                return Err(Error::new(
-                    other.span(),
-                    "unsupported type, expected a type path",
-                ))
-            }
+        let kind = match variant.fields {
+            Fields::Unnamed(fields) => VariantKind::Wrapped(Self::wrapped_type(fields, span)?),
+            Fields::Named(fields) => VariantKind::Inline(StructField::from_fields(fields)?),
+            Fields::Unit => VariantKind::Inline(Vec::new()),
         };
 
         let attribute = match find_attr(&variant.attrs, "command") {
@@ -74,32 +72,66 @@ impl ParsedVariant {
         Ok(Self {
             span,
             ident: variant.ident,
+            raw_attrs: variant.attrs,
             attribute,
-            inner,
+            kind,
         })
     }
+
+    /// Parse the type wrapped by a tuple variant
+    fn wrapped_type(fields: FieldsUnnamed, span: Span) -> Result<TypePath> {
+        if fields.unnamed.len() != 1 {
+            return Err(Error::new(
+                span,
+                "variant must have exactly one unnamed field",
+            ));
+        }
+
+        match &fields.unnamed[0].ty {
+            // Safety: len is checked above
+            Type::Path(ty) => Ok(ty.clone()),
+            other => Err(Error::new(
+                other.span(),
+                "unsupported type, expected a type path",
+            )),
+        }
+    }
 }
 
 /// Parsed variant attribute
 pub struct VariantAttribute {
     /// Name of the subcommand
     pub name: CommandName,
+    /// Localization dictionary for the subcommand name.
+    pub name_localizations: Option<FunctionPath>,
+    /// Description of the subcommand
+    pub desc: Option<CommandDescription>,
+    /// Localization dictionary for the subcommand description.
+    pub desc_localizations: Option<FunctionPath>,
 }
 
 impl VariantAttribute {
+    const VALID_ATTRIBUTES: &'static [&'static str] =
+        &["name", "name_localizations", "desc", "desc_localizations"];
+
     pub fn parse(attr: &Attribute) -> Result<Self> {
-        let mut parser = NamedAttrs::parse(attr, &["name"])?;
+        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
 
         Ok(Self {
             name: parser.required("name")?,
+            name_localizations: parser.optional("name_localizations")?,
+            desc: parser.optional("desc")?,
+            desc_localizations: parser.optional("desc_localizations")?,
         })
     }
 }
 
 /// Parsed type attribute
 pub struct TypeAttribute {
+    /// Whether the model is an autocomplete interaction model.
+    pub autocomplete: Option<bool>,
     /// Name of the command
-    pub name: CommandName,
+    pub name: Option<CommandName>,
     /// Localization dictionary for the command name.
     pub name_localizations: Option<FunctionPath>,
     /// Description of the command
@@ -120,6 +152,7 @@ pub struct TypeAttribute {
 
 impl TypeAttribute {
     const VALID_ATTRIBUTES: &'static [&'static str] = &[
+        "autocomplete",
         "name",
         "name_localizations",
         "desc",
@@ -135,7 +168,8 @@ impl TypeAttribute {
         let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
 
         Ok(Self {
-            name: parser.required("name")?,
+            autocomplete: parser.optional("autocomplete")?,
+            name: parser.optional("name")?,
             name_localizations: parser.optional("name_localizations")?,
             desc: parser.optional("desc")?,
             desc_localizations: parser.optional("desc_localizations")?,
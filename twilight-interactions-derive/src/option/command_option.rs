@@ -2,31 +2,69 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{spanned::Spanned, DataEnum, DeriveInput, Error, Ident, Result};
 
-use super::parse::{ChoiceKind, ChoiceValue, ParsedVariant};
+use super::{
+    create_option::choices_expr,
+    parse::{ChoiceKind, ChoiceValue, EnumAttribute, OtherVariant, ParsedChoices, ParsedVariant},
+    transparent,
+};
+use crate::localization::name_expr;
 
 /// Implementation of the `CommandOption` derive macro
 pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
     let ident = &input.ident;
     let input_span = input.span();
 
-    let (variants, kind) = match input.data {
+    let (attribute, choices) = match input.data {
         syn::Data::Enum(DataEnum { variants, .. }) => {
-            ParsedVariant::from_variants(variants, input_span)?
+            let attribute = EnumAttribute::parse(&input.attrs)?;
+            let choices = ParsedVariant::from_variants(variants, &attribute, input_span)?;
+
+            (attribute, choices)
         }
+        syn::Data::Struct(_) => return transparent::impl_command_option(input),
         _ => {
             return Err(Error::new(
                 input_span,
-                "`#[derive(CommandOption)] can only be applied to enums",
+                "`#[derive(CommandOption)] can only be applied to enums or transparent structs",
             ))
         }
     };
+    let ParsedChoices {
+        variants,
+        kind,
+        other,
+        autocomplete,
+    } = &choices;
+    let kind = *kind;
 
     let parsed_init = parsed_init(kind);
     let match_expr = match_expr(kind);
     let match_arms = variants.iter().map(variant_match_arm);
 
-    let value_match_arms = variants.iter().map(value_match_arm);
+    let fallback_arm = fallback_match_arm(kind, other.as_ref().map(|other| &other.ident));
+
+    let mut value_match_arms: Vec<_> = variants.iter().map(value_match_arm).collect();
+    if let Some(other) = other {
+        value_match_arms.push(other_value_match_arm(kind, &other.ident));
+    }
+    let other_value_fn = match other {
+        Some(other) => other_value_fn(kind, &other.ident),
+        None => TokenStream::new(),
+    };
+    let autocomplete_fn = if *autocomplete {
+        autocomplete_fn(variants)
+    } else {
+        TokenStream::new()
+    };
+    let helpers = if attribute.helpers {
+        helpers_impl(ident, &choices)
+    } else {
+        TokenStream::new()
+    };
+
+    // The value captured by the `other` variant is borrowed from `self`
     let choice_ty = match kind {
+        ChoiceKind::String if other.is_some() => quote! { &str },
         ChoiceKind::String => quote! { &'static str },
         ChoiceKind::Integer => quote! { i64 },
         ChoiceKind::Number => quote! { f64 },
@@ -43,11 +81,7 @@ pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
 
                 match #match_expr {
                     #(#match_arms,)*
-                    __other => ::std::result::Result::Err(
-                        ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
-                            ::std::string::ToString::to_string(__other)
-                        )
-                    )
+                    #fallback_arm
                 }
             }
         }
@@ -63,10 +97,106 @@ pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
                     #(#value_match_arms,)*
                 }
             }
+
+            #other_value_fn
+
+            #autocomplete_fn
         }
+
+        #helpers
     })
 }
 
+/// Generate the helpers enabled with the `helpers` attribute
+///
+/// This includes the `ALL` constant, the `name` and `localized_name` methods
+/// and the `FromStr` and `Display` implementations.
+fn helpers_impl(ident: &Ident, choices: &ParsedChoices) -> TokenStream {
+    let ParsedChoices {
+        variants,
+        kind,
+        other,
+        ..
+    } = choices;
+
+    let mut name_match_arms: Vec<_> = variants.iter().map(name_match_arm).collect();
+    if let Some(other) = other {
+        name_match_arms.push(other_name_match_arm(other));
+    }
+
+    let all_variants = variants.iter().map(|variant| &variant.ident);
+    let mut localized_name_arms: Vec<_> = variants.iter().map(localized_name_match_arm).collect();
+    if other.is_some() {
+        localized_name_arms.push(quote! { _ => ::std::option::Option::None });
+    }
+    let from_str = from_str_expr(*kind);
+
+    quote! {
+        impl #ident {
+            /// All the choices of the option.
+            ///
+            /// This constant is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub const ALL: &'static [Self] = &[#(Self::#all_variants),*];
+
+            /// Get the name of the choice corresponding to the current variant.
+            ///
+            /// This method is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(#name_match_arms,)*
+                }
+            }
+
+            /// Get the name of the choice in the given locale.
+            ///
+            /// The default name is returned if the choice has no localized
+            /// name for this locale.
+            ///
+            /// This method is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub fn localized_name(&self, locale: &str) -> ::std::borrow::Cow<'static, str> {
+                let __localizations: ::std::option::Option<::twilight_interactions::command::internal::LocalizationsInternal> = match self {
+                    #(#localized_name_arms,)*
+                };
+
+                match __localizations
+                    .and_then(|__name| __name.localizations)
+                    .and_then(|mut __localizations| __localizations.remove(locale))
+                {
+                    ::std::option::Option::Some(__name) => ::std::borrow::Cow::Owned(__name),
+                    ::std::option::Option::None => ::std::borrow::Cow::Borrowed(self.name()),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for #ident {
+            type Err = ::twilight_interactions::error::ParseOptionErrorType;
+
+            /// Parse a choice from its value.
+            fn from_str(__value: &str) -> ::std::result::Result<Self, Self::Err> {
+                let __value = #from_str;
+
+                ::twilight_interactions::command::CommandOption::from_option(
+                    __value,
+                    ::std::default::Default::default(),
+                    ::std::option::Option::None,
+                )
+            }
+        }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    }
+}
+
 /// Dummy implementation of the `CommandOption` trait in case of macro error
 pub fn dummy_command_option(ident: Ident, error: Error) -> TokenStream {
     let error = error.to_compile_error();
@@ -114,7 +244,7 @@ fn match_expr(kind: ChoiceKind) -> TokenStream {
 fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
     let ident = &variant.ident;
     let span = variant.span;
-    let value = match &variant.attribute.value {
+    let value = match &variant.value {
         ChoiceValue::String(val) => val.to_token_stream(),
         ChoiceValue::Int(val) => val.to_token_stream(),
         // https://stackoverflow.com/questions/45875142/what-are-the-alternatives-to-pattern-matching-floating-point-numbers
@@ -131,7 +261,7 @@ fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
 fn value_match_arm(variant: &ParsedVariant) -> TokenStream {
     let ident = &variant.ident;
     let span = variant.span;
-    let value = match &variant.attribute.value {
+    let value = match &variant.value {
         ChoiceValue::String(val) => val.to_token_stream(),
         ChoiceValue::Int(val) => val.to_token_stream(),
         ChoiceValue::Number(val) => val.to_token_stream(),
@@ -141,3 +271,154 @@ fn value_match_arm(variant: &ParsedVariant) -> TokenStream {
         Self::#ident => #value
     }
 }
+
+/// Generate the match arm for unknown values
+///
+/// Unknown values are captured by the `other` variant if there is one.
+fn fallback_match_arm(kind: ChoiceKind, other: Option<&Ident>) -> TokenStream {
+    match (kind, other) {
+        (ChoiceKind::String, Some(other)) => quote! {
+            __other => ::std::result::Result::Ok(Self::#other(::std::string::String::from(__other)))
+        },
+        (ChoiceKind::Integer | ChoiceKind::Number, Some(other)) => quote! {
+            __other => ::std::result::Result::Ok(Self::#other(*__other))
+        },
+        (_, None) => quote! {
+            __other => ::std::result::Result::Err(
+                ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
+                    ::std::string::ToString::to_string(__other)
+                )
+            )
+        },
+    }
+}
+
+/// Generate match arm for the `other` variant in value method
+fn other_value_match_arm(kind: ChoiceKind, other: &Ident) -> TokenStream {
+    match kind {
+        ChoiceKind::String => quote! { Self::#other(__value) => __value.as_str() },
+        ChoiceKind::Integer | ChoiceKind::Number => quote! { Self::#other(__value) => *__value },
+    }
+}
+
+/// Generate the method returning the value captured by the `other` variant
+fn other_value_fn(kind: ChoiceKind, other: &Ident) -> TokenStream {
+    let (ty, value) = match kind {
+        ChoiceKind::String => (quote! { &str }, quote! { __value.as_str() }),
+        ChoiceKind::Integer => (quote! { i64 }, quote! { *__value }),
+        ChoiceKind::Number => (quote! { f64 }, quote! { *__value }),
+    };
+
+    quote! {
+        /// Get the unknown value captured by the `other` variant.
+        ///
+        /// Returns [`None`] for other variants, unlike the
+        /// [`value`](Self::value) method which returns the value of any
+        /// variant.
+        ///
+        /// This method is automatically generated by the [`CommandOption`] derive macro.
+        ///
+        /// [`CommandOption`]: twilight_interactions::command::CommandOption
+        pub fn other_value(&self) -> ::std::option::Option<#ty> {
+            match self {
+                Self::#other(__value) => ::std::option::Option::Some(#value),
+                _ => ::std::option::Option::None,
+            }
+        }
+    }
+}
+
+/// Generate match arm for a variant in name method
+fn name_match_arm(variant: &ParsedVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let name = &variant.name;
+
+    quote_spanned! {variant.span=>
+        Self::#ident => #name
+    }
+}
+
+/// Generate match arm for the `other` variant in name method
+fn other_name_match_arm(other: &OtherVariant) -> TokenStream {
+    let ident = &other.ident;
+    let name = &other.name;
+
+    quote! { Self::#ident(_) => #name }
+}
+
+/// Generate match arm for a variant in localized_name method
+fn localized_name_match_arm(variant: &ParsedVariant) -> TokenStream {
+    let ident = &variant.ident;
+
+    match &variant.name_localizations {
+        Some(_) => {
+            let name_expr = name_expr(&variant.name, &variant.name_localizations);
+            quote! { Self::#ident => ::std::option::Option::Some(#name_expr) }
+        }
+        None => quote! { Self::#ident => ::std::option::Option::None },
+    }
+}
+
+/// Generate the `CommandOptionValue` parsed from a string in `FromStr`
+fn from_str_expr(kind: ChoiceKind) -> TokenStream {
+    let value_path =
+        quote!(::twilight_model::application::interaction::application_command::CommandOptionValue);
+
+    match kind {
+        ChoiceKind::String => quote! {
+            #value_path::String(::std::string::String::from(__value))
+        },
+        ChoiceKind::Integer => quote! {
+            match __value.trim().parse::<i64>() {
+                ::std::result::Result::Ok(__value) => #value_path::Integer(__value),
+                ::std::result::Result::Err(_) => return ::std::result::Result::Err(
+                    ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
+                        ::std::string::String::from(__value)
+                    )
+                ),
+            }
+        },
+        ChoiceKind::Number => quote! {
+            match __value.trim().parse::<f64>() {
+                ::std::result::Result::Ok(__value) => #value_path::Number(__value),
+                ::std::result::Result::Err(_) => return ::std::result::Result::Err(
+                    ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
+                        ::std::string::String::from(__value)
+                    )
+                ),
+            }
+        },
+    }
+}
+
+/// Generate autocomplete responder of choices provided with autocomplete
+fn autocomplete_fn(variants: &[ParsedVariant]) -> TokenStream {
+    let choices = choices_expr(variants);
+
+    quote! {
+        /// Get the choices matching the user input of an autocomplete interaction.
+        ///
+        /// Choices are ranked with a [`FuzzyMatcher`]. If a locale is given,
+        /// usually the locale of the user that triggered the interaction, the
+        /// input is matched against the names localized in this locale.
+        /// Otherwise, all the names of the choices are matched. At most 25
+        /// choices are returned.
+        ///
+        /// This method is automatically generated by the [`CommandOption`] derive
+        /// macro for choices provided with autocomplete.
+        ///
+        /// [`CommandOption`]: twilight_interactions::command::CommandOption
+        /// [`FuzzyMatcher`]: twilight_interactions::command::FuzzyMatcher
+        pub fn autocomplete(
+            input: &str,
+            locale: ::std::option::Option<&str>,
+        ) -> ::std::vec::Vec<::twilight_model::application::command::CommandOptionChoice> {
+            let mut __matcher = ::twilight_interactions::command::FuzzyMatcher::new(#choices);
+            if let ::std::option::Option::Some(__locale) = locale {
+                __matcher = __matcher.locale(__locale);
+            }
+
+            __matcher.matches(input)
+        }
+    }
+}
@@ -4,24 +4,39 @@ use syn::{spanned::Spanned, DeriveInput, Error, Ident, Result};
 
 use crate::localization::name_expr;
 
-use super::parse::{ChoiceKind, ChoiceValue, ParsedVariant};
+use super::{
+    parse::{ChoiceKind, ChoiceValue, EnumAttribute, ParsedChoices, ParsedVariant},
+    transparent,
+};
 
 pub fn impl_create_option(input: DeriveInput) -> Result<TokenStream> {
     let ident = &input.ident;
     let input_span = input.span();
 
-    let (variants, kind) = match input.data {
+    // The `other` variant is not registered as a choice
+    let ParsedChoices {
+        variants,
+        kind,
+        autocomplete,
+        ..
+    } = match input.data {
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            ParsedVariant::from_variants(variants, input_span)?
+            let attribute = EnumAttribute::parse(&input.attrs)?;
+            ParsedVariant::from_variants(variants, &attribute, input_span)?
         }
+        syn::Data::Struct(_) => return transparent::impl_create_option(input),
         _ => {
             return Err(Error::new(
                 input_span,
-                "`#[derive(CommandOption)] can only be applied to enums",
+                "`#[derive(CommandOption)] can only be applied to enums or transparent structs",
             ))
         }
     };
 
+    if autocomplete {
+        return Ok(autocomplete_create_option(ident, kind));
+    }
+
     let vec_capacity = variants.len();
     let choice_variants = variants.iter().map(choice_variant);
     let command_option = command_option(kind);
@@ -41,6 +56,46 @@ pub fn impl_create_option(input: DeriveInput) -> Result<TokenStream> {
     })
 }
 
+/// Generate the implementation for choices provided with autocomplete
+///
+/// Choices are provided by the generated autocomplete responder instead of
+/// being registered with the command.
+fn autocomplete_create_option(ident: &Ident, kind: ChoiceKind) -> TokenStream {
+    let opt_kind = match kind {
+        ChoiceKind::String => quote! { String },
+        ChoiceKind::Integer => quote! { Integer },
+        ChoiceKind::Number => quote! { Number },
+    };
+
+    quote! {
+        impl ::twilight_interactions::command::CreateOption for #ident {
+            fn create_option(
+                __data: ::twilight_interactions::command::internal::CreateOptionData,
+            ) -> ::twilight_model::application::command::CommandOption {
+                ::twilight_interactions::command::internal::CreateOptionData {
+                    autocomplete: true,
+                    ..__data
+                }
+                .into_option(::twilight_model::application::command::CommandOptionType::#opt_kind)
+            }
+        }
+    }
+}
+
+/// Generate an expression building the list of choices
+pub fn choices_expr(variants: &[ParsedVariant]) -> TokenStream {
+    let vec_capacity = variants.len();
+    let choice_variants = variants.iter().map(choice_variant);
+
+    quote! { {
+        let mut __choices = ::std::vec::Vec::with_capacity(#vec_capacity);
+
+        #(#choice_variants)*
+
+        __choices
+    } }
+}
+
 pub fn dummy_create_option(ident: Ident, error: Error) -> TokenStream {
     let error = error.to_compile_error();
 
@@ -59,10 +114,10 @@ pub fn dummy_create_option(ident: Ident, error: Error) -> TokenStream {
 
 /// Generate push instruction for a given variant
 fn choice_variant(variant: &ParsedVariant) -> TokenStream {
-    let name = String::from(variant.attribute.name.clone());
-    let name_expr = name_expr(&name, &variant.attribute.name_localizations);
+    let name = variant.name.clone();
+    let name_expr = name_expr(&name, &variant.name_localizations);
 
-    let value = match &variant.attribute.value {
+    let value = match &variant.value {
         ChoiceValue::String(val) => quote! { ::std::convert::From::from(#val) },
         ChoiceValue::Int(val) => val.to_token_stream(),
         ChoiceValue::Number(val) => val.to_token_stream(),
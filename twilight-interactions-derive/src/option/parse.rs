@@ -1,55 +1,210 @@
+use std::fmt::{self, Display, Formatter};
+
 use proc_macro2::{Ident, Span};
-use syn::{spanned::Spanned, Attribute, Error, Fields, Lit, Result, Variant};
+use syn::{spanned::Spanned, Attribute, Error, Fields, Lit, Result, Type, Variant};
 
 use crate::parse::{
     attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
     parsers::{ChoiceName, FunctionPath},
-    syntax::find_attr,
+    syntax::{find_attr, parse_doc},
 };
 
+/// Maximum number of static choices of a command option
+const MAX_CHOICES: usize = 25;
+
+/// Parsed enum attribute
+#[derive(Default)]
+pub struct EnumAttribute {
+    /// Case used to infer string values from variant identifiers
+    pub rename_all: Option<RenameRule>,
+    /// Whether integer values are inferred by auto-numbering variants
+    pub int: bool,
+    /// Whether choices are provided with autocomplete instead of static choices
+    pub autocomplete: bool,
+    /// Whether helper methods and trait implementations are generated
+    pub helpers: bool,
+}
+
+impl EnumAttribute {
+    /// Parse the enum attribute from the type attributes
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let Some(attr) = find_attr(attrs, "option") else {
+            return Ok(Self::default());
+        };
+
+        let mut parser =
+            NamedAttrs::parse(attr, &["rename_all", "int", "autocomplete", "helpers"])?;
+        let attribute = Self {
+            rename_all: parser.optional("rename_all")?,
+            int: parser.optional("int")?.unwrap_or_default(),
+            autocomplete: parser.optional("autocomplete")?.unwrap_or_default(),
+            helpers: parser.optional("helpers")?.unwrap_or_default(),
+        };
+
+        if attribute.rename_all.is_some() && attribute.int {
+            return Err(Error::new_spanned(
+                attr,
+                "`rename_all` and `int` are mutually exclusive",
+            ));
+        }
+
+        Ok(attribute)
+    }
+
+    /// Kind of the choices, if inferred from the enum attribute
+    fn kind(&self) -> Option<ChoiceKind> {
+        if self.int {
+            Some(ChoiceKind::Integer)
+        } else if self.rename_all.is_some() {
+            Some(ChoiceKind::String)
+        } else {
+            None
+        }
+    }
+}
+
 /// Parsed enum variants.
 pub struct ParsedVariant {
     pub span: Span,
     pub ident: Ident,
-    pub attribute: VariantAttribute,
+    pub name: String,
+    pub name_localizations: Option<FunctionPath>,
+    pub value: ChoiceValue,
     pub kind: ChoiceKind,
 }
 
+/// Parsed choice enum.
+pub struct ParsedChoices {
+    pub variants: Vec<ParsedVariant>,
+    pub kind: ChoiceKind,
+    /// Variant capturing unknown values (`#[option(other)]`)
+    pub other: Option<OtherVariant>,
+    /// Whether choices are provided with autocomplete
+    pub autocomplete: bool,
+}
+
+/// Parsed variant with the `#[option(other)]` attribute.
+pub struct OtherVariant {
+    pub ident: Ident,
+    pub name: String,
+    pub ty: Type,
+}
+
 impl ParsedVariant {
     /// Parse an iterator of syn [`Variant`].
     ///
-    /// The inferred [`OptionKind`] is also returned.
+    /// The inferred [`ChoiceKind`] and the `other` variant are also returned.
     pub fn from_variants(
         variants: impl IntoIterator<Item = Variant>,
+        attribute: &EnumAttribute,
         input_span: Span,
-    ) -> Result<(Vec<Self>, ChoiceKind)> {
-        let mut iter = variants.into_iter();
+    ) -> Result<ParsedChoices> {
+        let mut kind = attribute.kind();
+        let mut next_int = 0;
+        let mut parsed: Vec<Self> = Vec::new();
+        let mut other = None;
+
+        for variant in variants {
+            let variant_attribute = match find_attr(&variant.attrs, "option") {
+                Some(attr) => VariantAttribute::parse(attr, kind)?,
+                None => VariantAttribute::default(),
+            };
+
+            if variant_attribute.other {
+                if other.is_some() {
+                    return Err(Error::new_spanned(
+                        variant,
+                        "only one variant can have the `other` attribute",
+                    ));
+                }
+
+                other = Some(Self::other_variant(variant)?);
+                continue;
+            }
+
+            let variant = Self::from_variant(variant, variant_attribute, attribute, next_int)?;
 
-        // Parse the fist variant to infer the type
-        let first = match iter.next() {
-            Some(variant) => Self::from_variant(variant, None)?,
-            None => {
+            // Inferred values may collide with explicit ones
+            if let Some(duplicate) = parsed.iter().find(|parsed| parsed.value == variant.value) {
                 return Err(Error::new(
-                    input_span,
-                    "enum must have at least one variant",
-                ))
+                    variant.span,
+                    format!(
+                        "duplicate choice value {}, already used by `{}`",
+                        variant.value, duplicate.ident
+                    ),
+                ));
+            }
+
+            if let ChoiceValue::Int(value) = variant.value {
+                next_int = value.saturating_add(1);
             }
-        };
-        let choice_kind = first.kind;
 
-        // Parse other variants
-        let mut variants = vec![first];
-        for variant in iter {
-            variants.push(Self::from_variant(variant, Some(choice_kind))?);
+            // The type is inferred from the first variant if not set on the enum
+            kind = Some(variant.kind);
+            parsed.push(variant);
         }
 
-        Ok((variants, choice_kind))
+        match kind {
+            Some(kind) if !parsed.is_empty() => {
+                if let Some(other) = &other {
+                    other.check_type(kind)?;
+                }
+
+                if parsed.len() > MAX_CHOICES && !attribute.autocomplete {
+                    return Err(Error::new(
+                        input_span,
+                        format!(
+                            "enum has {} choices but Discord allows at most {MAX_CHOICES}, \
+                             use `#[option(autocomplete)]` to provide them with autocomplete",
+                            parsed.len()
+                        ),
+                    ));
+                }
+
+                Ok(ParsedChoices {
+                    autocomplete: attribute.autocomplete,
+                    variants: parsed,
+                    kind,
+                    other,
+                })
+            }
+            _ => Err(Error::new(
+                input_span,
+                "enum must have at least one variant",
+            )),
+        }
+    }
+
+    /// Parse a variant with the `#[option(other)]` attribute.
+    ///
+    /// The variant must be a tuple variant with a single field.
+    fn other_variant(variant: Variant) -> Result<OtherVariant> {
+        let ty = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed[0].ty.clone(),
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "`other` variant must be a tuple variant with a single field",
+                ))
+            }
+        };
+
+        Ok(OtherVariant {
+            name: variant_name(&variant)?,
+            ident: variant.ident,
+            ty,
+        })
     }
 
     /// Parse a single syn [`Variant`].
     ///
-    /// If no [`ChoiceKind`] is provided, the type is inferred from value.
-    fn from_variant(variant: Variant, kind: Option<ChoiceKind>) -> Result<Self> {
+    /// Values are inferred from the enum attribute if not set.
+    fn from_variant(
+        variant: Variant,
+        attribute: VariantAttribute,
+        enum_attribute: &EnumAttribute,
+        next_int: i64,
+    ) -> Result<Self> {
         if !matches!(variant.fields, Fields::Unit) {
             return Err(Error::new_spanned(
                 variant,
@@ -57,45 +212,92 @@ impl ParsedVariant {
             ));
         }
 
-        let attribute = match find_attr(&variant.attrs, "option") {
-            Some(attr) => VariantAttribute::parse(attr, kind)?,
-            None => {
-                return Err(Error::new(
-                    variant.span(),
-                    "missing required #[option(...)] attribute",
-                ))
-            }
+        let span = variant.span();
+
+        let value = match attribute.value {
+            Some(value) => value,
+            None if enum_attribute.int => ChoiceValue::Int(next_int),
+            None => match &enum_attribute.rename_all {
+                Some(rule) => ChoiceValue::String(rule.apply(&variant.ident.to_string())),
+                None => return Err(Error::new(span, "missing required `value` argument")),
+            },
+        };
+
+        let name = match attribute.name {
+            Some(name) => String::from(name),
+            None => variant_name(&variant)?,
         };
 
         Ok(Self {
-            span: variant.span(),
+            span,
             ident: variant.ident,
-            kind: attribute.value.kind(),
-            attribute,
+            name,
+            name_localizations: attribute.name_localizations,
+            kind: value.kind(),
+            value,
         })
     }
 }
 
+impl OtherVariant {
+    /// Ensure the field type matches the type of the choices
+    fn check_type(&self, kind: ChoiceKind) -> Result<()> {
+        let expected = kind.field_type();
+        let valid = match &self.ty {
+            Type::Path(path) if path.qself.is_none() => {
+                path.path.segments.last().is_some_and(|segment| {
+                    segment.ident == expected && segment.arguments.is_empty()
+                })
+            }
+            _ => false,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::new_spanned(
+                &self.ty,
+                format!(
+                    "`other` variant field must be `{expected}` for {} choices",
+                    kind.name()
+                ),
+            ))
+        }
+    }
+}
+
+/// Infer the name of a variant from its documentation comment or identifier
+fn variant_name(variant: &Variant) -> Result<String> {
+    match find_attr(&variant.attrs, "doc") {
+        Some(_) => parse_doc(&variant.attrs, variant.span()),
+        None => Ok(title_case(&variant.ident.to_string())),
+    }
+}
+
 /// Parsed variant attribute
+#[derive(Default)]
 pub struct VariantAttribute {
     /// Name of the choice (shown to users)
-    pub name: ChoiceName,
+    pub name: Option<ChoiceName>,
     /// Localizations dictionary for the choice name
     pub name_localizations: Option<FunctionPath>,
     /// Value of the choice
-    pub value: ChoiceValue,
+    pub value: Option<ChoiceValue>,
+    /// Whether the variant captures unknown values
+    pub other: bool,
 }
 
 impl VariantAttribute {
     /// Parse a single [`Attribute`].
     ///
-    /// If no [`ChoiceKind`] is provided, the type is inferred from value.
+    /// If a [`ChoiceKind`] is provided, the value must be of this type.
     pub fn parse(attr: &Attribute, kind: Option<ChoiceKind>) -> Result<Self> {
-        let mut parser = NamedAttrs::parse(attr, &["name", "name_localizations", "value"])?;
+        let mut parser =
+            NamedAttrs::parse(attr, &["name", "name_localizations", "value", "other"])?;
 
         // Ensure the parsed type is the same as the inferred one
-        let value: ParseSpanned<ChoiceValue> = parser.required("value")?;
-        if let Some(kind) = kind {
+        let value: Option<ParseSpanned<ChoiceValue>> = parser.optional("value")?;
+        if let (Some(value), Some(kind)) = (&value, kind) {
             if value.inner.kind() != kind {
                 return Err(Error::new(
                     value.span,
@@ -104,16 +306,30 @@ impl VariantAttribute {
             }
         }
 
-        Ok(Self {
-            name: parser.required("name")?,
+        let attribute = Self {
+            name: parser.optional("name")?,
             name_localizations: parser.optional("name_localizations")?,
-            value: value.inner,
-        })
+            value: value.map(|value| value.inner),
+            other: parser.optional("other")?.unwrap_or_default(),
+        };
+
+        if attribute.other
+            && (attribute.name.is_some()
+                || attribute.name_localizations.is_some()
+                || attribute.value.is_some())
+        {
+            return Err(Error::new_spanned(
+                attr,
+                "`other` cannot be combined with other arguments",
+            ));
+        }
+
+        Ok(attribute)
     }
 }
 
 /// Value of a parsed choice
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ChoiceValue {
     String(String),
     Int(i64),
@@ -131,6 +347,16 @@ impl ChoiceValue {
     }
 }
 
+impl Display for ChoiceValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ChoiceValue::String(value) => write!(f, "{value:?}"),
+            ChoiceValue::Int(value) => write!(f, "{value}"),
+            ChoiceValue::Number(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
 impl ParseAttribute for ChoiceValue {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let parsed = match input {
@@ -166,4 +392,139 @@ impl ChoiceKind {
             ChoiceKind::Number => "float",
         }
     }
+
+    /// Get the Rust type of the values
+    fn field_type(&self) -> &'static str {
+        match self {
+            ChoiceKind::String => "String",
+            ChoiceKind::Integer => "i64",
+            ChoiceKind::Number => "f64",
+        }
+    }
+}
+
+/// Case conversion rule of the `rename_all` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lowercase,
+    Uppercase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    CamelCase,
+    PascalCase,
+}
+
+impl ParseAttribute for RenameRule {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match &*spanned.inner {
+            "lowercase" => Ok(Self::Lowercase),
+            "UPPERCASE" => Ok(Self::Uppercase),
+            "snake_case" => Ok(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "kebab-case" => Ok(Self::KebabCase),
+            "camelCase" => Ok(Self::CamelCase),
+            "PascalCase" => Ok(Self::PascalCase),
+            invalid => Err(spanned.error(format!(
+                "`{invalid}` is not a valid case (expected one of lowercase, UPPERCASE, \
+                snake_case, SCREAMING_SNAKE_CASE, kebab-case, camelCase, PascalCase)"
+            ))),
+        }
+    }
+}
+
+impl RenameRule {
+    /// Apply the rule to a variant identifier
+    pub fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+
+        match self {
+            Self::Lowercase => words.concat().to_lowercase(),
+            Self::Uppercase => words.concat().to_uppercase(),
+            Self::SnakeCase => words.join("_").to_lowercase(),
+            Self::ScreamingSnakeCase => words.join("_").to_uppercase(),
+            Self::KebabCase => words.join("-").to_lowercase(),
+            Self::CamelCase => {
+                let mut words = words.into_iter();
+                let first = words.next().unwrap_or_default().to_lowercase();
+
+                first + &words.map(capitalize).collect::<String>()
+            }
+            Self::PascalCase => words.into_iter().map(capitalize).collect(),
+        }
+    }
+}
+
+/// Convert a variant identifier into a title-cased name
+///
+/// Words are separated by spaces and acronyms are kept as-is, so `UsEast`
+/// becomes `Us East` and `HTTPServer` becomes `HTTP Server`.
+fn title_case(ident: &str) -> String {
+    split_words(ident)
+        .into_iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Capitalize the first letter of a word and lowercase the others
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Split an identifier into words
+///
+/// Words are delimited by underscores and case changes. Consecutive uppercase
+/// letters are kept in the same word (`HTTPServer` is split into `HTTP` and
+/// `Server`), and digits are attached to the previous word.
+fn split_words(ident: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = ident.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for (i, &(index, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if start < index {
+                words.push(&ident[start..index]);
+            }
+            start = index + 1;
+            continue;
+        }
+
+        let Some(&(_, prev)) = i.checked_sub(1).and_then(|i| chars.get(i)) else {
+            continue;
+        };
+        let next = chars.get(i + 1).map(|&(_, c)| c);
+
+        let boundary = c.is_uppercase()
+            && (prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next.is_some_and(char::is_lowercase)));
+
+        if boundary && start < index {
+            words.push(&ident[start..index]);
+            start = index;
+        }
+    }
+
+    if start < ident.len() {
+        words.push(&ident[start..]);
+    }
+
+    words
 }
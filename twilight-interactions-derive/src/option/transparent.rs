@@ -0,0 +1,219 @@
+//! Implementation of `CommandOption` and `CreateOption` derive macros for
+//! transparent newtypes.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Generics, Result, Type};
+
+use crate::parse::{
+    attribute::NamedAttrs,
+    option_data::OptionData,
+    parsers::{CommandDescription, FunctionPath},
+    syntax::find_attr,
+};
+
+/// Parsed transparent struct
+pub struct TransparentStruct {
+    pub ident: syn::Ident,
+    pub generics: Generics,
+    pub inner: Type,
+    pub inner_span: Span,
+    pub attribute: TransparentAttribute,
+}
+
+impl TransparentStruct {
+    /// Parse a [`DeriveInput`] as a [`TransparentStruct`]
+    ///
+    /// The struct must have a single unnamed field and the
+    /// `#[option(transparent)]` attribute.
+    pub fn parse(input: DeriveInput) -> Result<Self> {
+        let span = input.span();
+        let Data::Struct(data) = input.data else {
+            return Err(Error::new(span, "expected a struct"));
+        };
+
+        let attribute = match find_attr(&input.attrs, "option") {
+            Some(attr) => TransparentAttribute::parse(attr)?,
+            None => TransparentAttribute::default(),
+        };
+
+        if !attribute.transparent {
+            return Err(Error::new(
+                span,
+                "structs must have the `#[option(transparent)]` attribute",
+            ));
+        }
+
+        let inner = match data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                fields.unnamed.into_iter().next().unwrap().ty
+            }
+            fields => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "transparent options must be tuple structs with a single field",
+                ))
+            }
+        };
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            inner_span: inner.span(),
+            inner,
+            attribute,
+        })
+    }
+}
+
+/// Parsed transparent struct attribute
+#[derive(Default)]
+pub struct TransparentAttribute {
+    /// Whether the struct is a transparent option
+    pub transparent: bool,
+    /// Default description of the option
+    pub desc: Option<CommandDescription>,
+    /// Default localization dictionary for the option description
+    pub desc_localizations: Option<FunctionPath>,
+    /// Function used to validate the parsed value
+    pub validate: Option<FunctionPath>,
+    /// Default data of the option
+    pub data: OptionData,
+}
+
+impl TransparentAttribute {
+    const VALID_ATTRIBUTES: &'static [&'static str] =
+        &["transparent", "desc", "desc_localizations", "validate"];
+
+    /// Parse a single [`Attribute`]
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let valid = [Self::VALID_ATTRIBUTES, OptionData::VALID_ATTRIBUTES].concat();
+        let mut parser = NamedAttrs::parse(attr, &valid)?;
+
+        let attribute = Self {
+            transparent: parser.optional("transparent")?.unwrap_or_default(),
+            desc: parser.optional("desc")?,
+            desc_localizations: parser.optional("desc_localizations")?,
+            validate: parser.optional("validate")?,
+            data: OptionData::parse(&mut parser)?,
+        };
+
+        if attribute.desc.is_some() && attribute.desc_localizations.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "`desc` and `desc_localizations` are mutually exclusive",
+            ));
+        }
+
+        Ok(attribute)
+    }
+}
+
+/// Implementation of the `CommandOption` derive macro for transparent structs
+pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
+    let input = TransparentStruct::parse(input)?;
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let inner = &input.inner;
+    let span = input.inner_span;
+
+    let default_data = input.attribute.data.to_tokens(quote_spanned! {span=>
+        <#inner as ::twilight_interactions::command::CommandOption>::default_data()
+    });
+
+    let validate = match &input.attribute.validate {
+        Some(path) => quote! {
+            if let ::std::result::Result::Err(__error) = #path(&__value) {
+                return ::std::result::Result::Err(
+                    ::twilight_interactions::error::ParseOptionErrorType::Custom(
+                        ::std::string::ToString::to_string(&__error)
+                    )
+                );
+            }
+        },
+        None => TokenStream::new(),
+    };
+
+    Ok(quote_spanned! {span=>
+        impl #generics ::twilight_interactions::command::CommandOption for #ident #generics #where_clause {
+            const SETTINGS: ::twilight_interactions::command::internal::SupportedSettings =
+                <#inner as ::twilight_interactions::command::CommandOption>::SETTINGS;
+
+            fn from_option(
+                __value: ::twilight_model::application::interaction::application_command::CommandOptionValue,
+                __data: ::twilight_interactions::command::internal::CommandOptionData,
+                __resolved: ::std::option::Option<&::twilight_model::application::interaction::InteractionDataResolved>
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseOptionErrorType> {
+                Self::from_option_with(
+                    __value,
+                    __data,
+                    &::twilight_interactions::command::internal::OptionSettings::new(),
+                    __resolved,
+                )
+            }
+
+            fn from_option_with(
+                __value: ::twilight_model::application::interaction::application_command::CommandOptionValue,
+                __data: ::twilight_interactions::command::internal::CommandOptionData,
+                __settings: &::twilight_interactions::command::internal::OptionSettings,
+                __resolved: ::std::option::Option<&::twilight_model::application::interaction::InteractionDataResolved>
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseOptionErrorType> {
+                let __value: #inner = ::twilight_interactions::command::CommandOption::from_option_with(__value, __data, __settings, __resolved)?;
+
+                #validate
+
+                ::std::result::Result::Ok(Self(__value))
+            }
+
+            fn default_data() -> ::twilight_interactions::command::internal::CommandOptionData {
+                #default_data
+            }
+        }
+    })
+}
+
+/// Implementation of the `CreateOption` derive macro for transparent structs
+pub fn impl_create_option(input: DeriveInput) -> Result<TokenStream> {
+    let input = TransparentStruct::parse(input)?;
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let inner = &input.inner;
+    let span = input.inner_span;
+
+    let default_data = input.attribute.data.to_tokens(quote_spanned! {span=>
+        <#inner as ::twilight_interactions::command::CreateOption>::default_data()
+    });
+
+    let default_description = match (&input.attribute.desc, &input.attribute.desc_localizations) {
+        (Some(desc), _) => quote! {
+            ::std::option::Option::Some(::twilight_interactions::command::DescLocalizations::new(
+                #desc,
+                ::std::iter::empty::<(&str, &str)>(),
+            ))
+        },
+        (None, Some(path)) => quote! { ::std::option::Option::Some(#path()) },
+        (None, None) => quote! {
+            <#inner as ::twilight_interactions::command::CreateOption>::default_description()
+        },
+    };
+
+    Ok(quote_spanned! {span=>
+        impl #generics ::twilight_interactions::command::CreateOption for #ident #generics #where_clause {
+            fn create_option(
+                __data: ::twilight_interactions::command::internal::CreateOptionData,
+            ) -> ::twilight_model::application::command::CommandOption {
+                <#inner as ::twilight_interactions::command::CreateOption>::create_option(__data)
+            }
+
+            fn default_data() -> ::twilight_interactions::command::internal::CommandOptionData {
+                #default_data
+            }
+
+            fn default_description() -> ::std::option::Option<::twilight_interactions::command::DescLocalizations> {
+                #default_description
+            }
+        }
+    })
+}
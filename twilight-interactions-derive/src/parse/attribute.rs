@@ -9,12 +9,13 @@
 use std::fmt::Display;
 
 use proc_macro2::{Ident, Span};
-use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Error, Lit, Result};
+use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Error, Lit, LitBool, Result, Token};
 
 /// Parse a list of named attributes like `#[command(rename = "name")]`.
 ///
-/// This only support `(ident) = (literal)` syntax for simplicity. Collected
-/// values can be parsed using the `optional` and `required` methods.
+/// This only support `(ident) = (literal)` syntax for simplicity, and bare
+/// `(ident)` flags that are parsed as `(ident) = true`. Collected values can
+/// be parsed using the `optional` and `required` methods.
 pub struct NamedAttrs {
     attr_span: Span,
     values: Vec<(Ident, Lit)>,
@@ -46,7 +47,11 @@ impl NamedAttrs {
             ));
         };
 
-        let lit: Lit = meta.value()?.parse()?;
+        let lit = if meta.input.is_empty() || meta.input.peek(Token![,]) {
+            Lit::Bool(LitBool::new(true, ident.span()))
+        } else {
+            meta.value()?.parse()?
+        };
         self.values.push((ident.clone(), lit));
 
         Ok(())
@@ -64,6 +69,18 @@ impl NamedAttrs {
         Ok(Some(parsed))
     }
 
+    /// Parse all occurrences of a repeatable attribute using the specified
+    /// parser function.
+    pub fn all<T: ParseAttribute>(&mut self, name: &str) -> Result<Vec<T>> {
+        let mut parsed = Vec::new();
+
+        while let Some(value) = self.optional(name)? {
+            parsed.push(value);
+        }
+
+        Ok(parsed)
+    }
+
     /// Parse a required attribute using the specified parser function.
     ///
     /// If the attribute is not found, an error is returned.
@@ -1,5 +1,6 @@
 //! Logic used to parse macro input across the crate.
 
 pub mod attribute;
+pub mod option_data;
 pub mod parsers;
 pub mod syntax;
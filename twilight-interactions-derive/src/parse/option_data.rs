@@ -0,0 +1,340 @@
+//! Parsing of command option data attributes
+//!
+//! Data attributes are shared between command model fields and transparent
+//! options, and correspond to the `CommandOptionData` type. Settings attributes
+//! are only available on fields, and correspond to the `OptionSettings` type.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Lit, Result};
+
+use super::attribute::{NamedAttrs, ParseAttribute, ParseSpanned};
+
+/// Parsed command option data
+#[derive(Default)]
+pub struct OptionData {
+    /// Limit to specific channel types
+    pub channel_types: Vec<ChannelType>,
+    /// Maximum value permitted
+    pub max_value: Option<CommandOptionValue>,
+    /// Minimum value permitted
+    pub min_value: Option<CommandOptionValue>,
+    /// Maximum string length
+    pub max_length: Option<u16>,
+    /// Minimum string length
+    pub min_length: Option<u16>,
+}
+
+impl OptionData {
+    pub const VALID_ATTRIBUTES: &'static [&'static str] = &[
+        "channel_types",
+        "max_value",
+        "min_value",
+        "max_length",
+        "min_length",
+    ];
+
+    /// Parse the option data attributes from a [`NamedAttrs`] parser
+    pub fn parse(parser: &mut NamedAttrs) -> Result<Self> {
+        Ok(Self {
+            channel_types: parser.optional("channel_types")?.unwrap_or_default(),
+            max_value: parser.optional("max_value")?,
+            min_value: parser.optional("min_value")?,
+            max_length: parser.optional("max_length")?,
+            min_length: parser.optional("min_length")?,
+        })
+    }
+
+    /// Generate the `CommandOptionData` expression
+    ///
+    /// Only values set with attributes are included, others are taken from
+    /// the `defaults` expression (the default data of the option type).
+    pub fn to_tokens(&self, defaults: TokenStream) -> TokenStream {
+        let mut fields = Vec::new();
+
+        if !self.channel_types.is_empty() {
+            let items = self.channel_types.iter().map(channel_type);
+            fields.push(
+                quote! { channel_types: ::std::option::Option::Some(::std::vec![#(#items),*]) },
+            );
+        }
+        if let Some(max_value) = self.max_value {
+            let max_value = command_option_value(Some(max_value));
+            fields.push(quote! { max_value: #max_value });
+        }
+        if let Some(min_value) = self.min_value {
+            let min_value = command_option_value(Some(min_value));
+            fields.push(quote! { min_value: #min_value });
+        }
+        if let Some(max_length) = self.max_length {
+            fields.push(quote! { max_length: ::std::option::Option::Some(#max_length) });
+        }
+        if let Some(min_length) = self.min_length {
+            fields.push(quote! { min_length: ::std::option::Option::Some(#min_length) });
+        }
+
+        quote! {
+            ::twilight_interactions::command::internal::CommandOptionData {
+                #(#fields,)*
+                ..#defaults
+            }
+        }
+    }
+}
+
+/// Parsed command option settings
+#[derive(Default)]
+pub struct OptionSettings {
+    /// Accepted date and time formats
+    pub formats: Option<Vec<DateTimeFormat>>,
+    /// Separator between list items
+    pub separator: Option<String>,
+    /// Maximum number of list items
+    pub max_items: Option<u16>,
+    /// Minimum number of list items
+    pub min_items: Option<u16>,
+    /// Require the value to belong to the current guild
+    pub same_guild: bool,
+}
+
+impl OptionSettings {
+    pub const VALID_ATTRIBUTES: &'static [&'static str] = &[
+        "formats",
+        "separator",
+        "max_items",
+        "min_items",
+        "same_guild",
+    ];
+
+    /// Parse the option settings attributes from a [`NamedAttrs`] parser
+    pub fn parse(parser: &mut NamedAttrs) -> Result<Self> {
+        Ok(Self {
+            formats: parser.optional("formats")?,
+            separator: parse_separator(parser.optional("separator")?)?,
+            max_items: parser.optional("max_items")?,
+            min_items: parser.optional("min_items")?,
+            same_guild: parser.optional("same_guild")?.unwrap_or_default(),
+        })
+    }
+
+    /// Generate the `OptionSettings` expression
+    ///
+    /// Returns [`None`] if no settings are set, so the option can be parsed
+    /// without settings. The expression may use the `__context` variable of
+    /// the generated code.
+    pub fn to_tokens(&self) -> Option<TokenStream> {
+        let mut calls = Vec::new();
+
+        if let Some(formats) = &self.formats {
+            let items = formats.iter().map(datetime_format);
+            calls.push(quote! { .formats(::std::vec![#(#items),*]) });
+        }
+        if let Some(separator) = &self.separator {
+            calls.push(quote! { .separator(#separator) });
+        }
+        if let Some(max_items) = self.max_items {
+            calls.push(quote! { .max_items(#max_items) });
+        }
+        if let Some(min_items) = self.min_items {
+            calls.push(quote! { .min_items(#min_items) });
+        }
+        if self.same_guild {
+            calls.push(quote! { .same_guild(__context.guild_id) });
+        }
+
+        if calls.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            ::twilight_interactions::command::internal::OptionSettings::new() #(#calls)*
+        })
+    }
+}
+
+/// Ensure the list separator is not empty
+fn parse_separator(separator: Option<ParseSpanned<String>>) -> Result<Option<String>> {
+    match separator {
+        Some(separator) if separator.inner.is_empty() => {
+            Err(separator.error("separator cannot be empty"))
+        }
+        separator => Ok(separator.map(|separator| separator.inner)),
+    }
+}
+
+/// Parsed channel type
+pub enum ChannelType {
+    GuildText,
+    Private,
+    GuildVoice,
+    Group,
+    GuildCategory,
+    GuildAnnouncement,
+    GuildStore,
+    AnnouncementThread,
+    PublicThread,
+    PrivateThread,
+    GuildStageVoice,
+    GuildDirectory,
+    GuildForum,
+    GuildMedia,
+}
+
+impl ParseAttribute for Vec<ChannelType> {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        spanned
+            .inner
+            .split_ascii_whitespace()
+            .map(|value| ChannelType::parse(value, spanned.span))
+            .collect()
+    }
+}
+
+impl ChannelType {
+    fn parse(value: &str, span: Span) -> Result<Self> {
+        match value {
+            "guild_text" => Ok(Self::GuildText),
+            "private" => Ok(Self::Private),
+            "guild_voice" => Ok(Self::GuildVoice),
+            "group" => Ok(Self::Group),
+            "guild_category" => Ok(Self::GuildCategory),
+            "guild_announcement" | "guild_news" => Ok(Self::GuildAnnouncement),
+            "guild_store" => Ok(Self::GuildStore),
+            "announcement_thread" | "guild_news_thread" => Ok(Self::AnnouncementThread),
+            "public_thread" | "guild_public_thread" => Ok(Self::PublicThread),
+            "private_thread" | "guild_private_thread" => Ok(Self::PrivateThread),
+            "guild_stage_voice" => Ok(Self::GuildStageVoice),
+            "guild_directory" => Ok(Self::GuildDirectory),
+            "guild_forum" => Ok(Self::GuildForum),
+            "guild_media" => Ok(Self::GuildMedia),
+            invalid => Err(Error::new(
+                span,
+                format!("`{invalid}` is not a valid channel type"),
+            )),
+        }
+    }
+}
+
+/// Parsed date and time format
+pub enum DateTimeFormat {
+    Rfc3339,
+    DateTime,
+    Date,
+    Timestamp,
+}
+
+impl ParseAttribute for Vec<DateTimeFormat> {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        let formats = spanned
+            .inner
+            .split_ascii_whitespace()
+            .map(|value| DateTimeFormat::parse(value, spanned.span))
+            .collect::<Result<Vec<_>>>()?;
+
+        if formats.is_empty() {
+            return Err(spanned.error("expected at least one format"));
+        }
+
+        Ok(formats)
+    }
+}
+
+impl DateTimeFormat {
+    fn parse(value: &str, span: Span) -> Result<Self> {
+        match value {
+            "rfc3339" => Ok(Self::Rfc3339),
+            "date_time" => Ok(Self::DateTime),
+            "date" => Ok(Self::Date),
+            "timestamp" => Ok(Self::Timestamp),
+            invalid => Err(Error::new(
+                span,
+                format!("`{invalid}` is not a valid date and time format"),
+            )),
+        }
+    }
+}
+
+/// Parsed command option value
+#[derive(Clone, Copy)]
+pub enum CommandOptionValue {
+    Integer(i64),
+    Number(f64),
+}
+
+impl ParseAttribute for CommandOptionValue {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        match input {
+            Lit::Int(inner) => Ok(Self::Integer(inner.base10_parse()?)),
+            Lit::Float(inner) => Ok(Self::Number(inner.base10_parse()?)),
+            _ => Err(Error::new_spanned(
+                input,
+                "expected integer or floating point literal",
+            )),
+        }
+    }
+}
+
+/// Convert a [`ChannelType`] into a [`TokenStream`]
+pub fn channel_type(kind: &ChannelType) -> TokenStream {
+    match kind {
+        ChannelType::GuildText => quote!(::twilight_model::channel::ChannelType::GuildText),
+        ChannelType::Private => quote!(::twilight_model::channel::ChannelType::Private),
+        ChannelType::GuildVoice => quote!(::twilight_model::channel::ChannelType::GuildVoice),
+        ChannelType::Group => quote!(::twilight_model::channel::ChannelType::Group),
+        ChannelType::GuildCategory => quote!(::twilight_model::channel::ChannelType::GuildCategory),
+        ChannelType::GuildAnnouncement => {
+            quote!(::twilight_model::channel::ChannelType::GuildAnnouncement)
+        }
+        ChannelType::GuildStore => quote!(::twilight_model::channel::ChannelType::GuildStore),
+        ChannelType::AnnouncementThread => {
+            quote!(::twilight_model::channel::ChannelType::AnnouncementThread)
+        }
+        ChannelType::PublicThread => {
+            quote!(::twilight_model::channel::ChannelType::PublicThread)
+        }
+        ChannelType::PrivateThread => {
+            quote!(::twilight_model::channel::ChannelType::PrivateThread)
+        }
+        ChannelType::GuildStageVoice => {
+            quote!(::twilight_model::channel::ChannelType::GuildStageVoice)
+        }
+        ChannelType::GuildDirectory => {
+            quote!(::twilight_model::channel::ChannelType::GuildDirectory)
+        }
+        ChannelType::GuildForum => quote!(::twilight_model::channel::ChannelType::GuildForum),
+        ChannelType::GuildMedia => quote!(::twilight_model::channel::ChannelType::GuildMedia),
+    }
+}
+
+/// Convert a [`DateTimeFormat`] into a [`TokenStream`]
+fn datetime_format(format: &DateTimeFormat) -> TokenStream {
+    match format {
+        DateTimeFormat::Rfc3339 => {
+            quote!(::twilight_interactions::command::DateTimeFormat::Rfc3339)
+        }
+        DateTimeFormat::DateTime => {
+            quote!(::twilight_interactions::command::DateTimeFormat::DateTime)
+        }
+        DateTimeFormat::Date => quote!(::twilight_interactions::command::DateTimeFormat::Date),
+        DateTimeFormat::Timestamp => {
+            quote!(::twilight_interactions::command::DateTimeFormat::Timestamp)
+        }
+    }
+}
+
+/// Convert a [`Option<CommandOptionValue>`] into a [`TokenStream`]
+pub fn command_option_value(value: Option<CommandOptionValue>) -> TokenStream {
+    match value {
+        None => quote!(::std::option::Option::None),
+        Some(CommandOptionValue::Integer(inner)) => {
+            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Integer(#inner)))
+        }
+        Some(CommandOptionValue::Number(inner)) => {
+            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Number(#inner)))
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{Error, Lit, Path, Result};
+use syn::{Error, Ident, Lit, Path, Result};
 
 use super::attribute::{ParseAttribute, ParseSpanned};
 
@@ -26,6 +26,26 @@ impl ToTokens for FunctionPath {
     }
 }
 
+/// Name of a generated type.
+#[derive(Clone)]
+pub struct TypeName(Ident);
+
+impl ParseAttribute for TypeName {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let Lit::Str(lit) = input else {
+            return Err(Error::new_spanned(input, "expected string literal"));
+        };
+
+        Ok(Self(lit.parse()?))
+    }
+}
+
+impl From<TypeName> for Ident {
+    fn from(value: TypeName) -> Self {
+        value.0
+    }
+}
+
 /// Slash command or command option name.
 ///
 /// The following requirements are validated:
@@ -0,0 +1,181 @@
+use std::ops::{Deref, DerefMut};
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{
+            application_command::CommandOptionValue, InteractionChannel, InteractionDataResolved,
+        },
+    },
+    channel::ChannelType,
+    id::{marker::ChannelMarker, Id},
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// Narrow the channel types set with the `channel_types` attribute to the
+/// types accepted by a wrapper.
+///
+/// The returned list is empty if no type is accepted by the wrapper.
+fn narrow_channel_types(
+    channel_types: Option<Vec<ChannelType>>,
+    allowed: &[ChannelType],
+) -> Vec<ChannelType> {
+    match channel_types {
+        Some(channel_types) => channel_types
+            .into_iter()
+            .filter(|kind| allowed.contains(kind))
+            .collect(),
+        None => allowed.to_vec(),
+    }
+}
+
+macro_rules! channel_wrapper {
+    ($(#[$meta:meta])* $name:ident => [$($kind:ident),+]) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name(pub InteractionChannel);
+
+        impl $name {
+            /// Channel types accepted by this option.
+            pub const CHANNEL_TYPES: &'static [ChannelType] = &[$(ChannelType::$kind),+];
+
+            /// ID of the channel.
+            pub const fn id(&self) -> Id<ChannelMarker> {
+                self.0.id
+            }
+
+            /// Consume the wrapper and return the inner channel.
+            pub fn into_inner(self) -> InteractionChannel {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = InteractionChannel;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<$name> for InteractionChannel {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl CommandOption for $name {
+            fn from_option(
+                value: CommandOptionValue,
+                mut data: CommandOptionData,
+                resolved: Option<&InteractionDataResolved>,
+            ) -> Result<Self, ParseOptionErrorType> {
+                data.channel_types = Some(narrow_channel_types(
+                    data.channel_types,
+                    Self::CHANNEL_TYPES,
+                ));
+
+                InteractionChannel::from_option(value, data, resolved).map(Self)
+            }
+        }
+
+        impl CreateOption for $name {
+            fn create_option(mut data: CreateOptionData) -> TwilightCommandOption {
+                let channel_types =
+                    narrow_channel_types(data.data.channel_types, Self::CHANNEL_TYPES);
+
+                // An empty list would allow all channel types
+                assert!(
+                    !channel_types.is_empty(),
+                    "channel types of option `{}` do not include any type accepted by `{}`",
+                    data.name,
+                    stringify!($name),
+                );
+
+                data.data.channel_types = Some(channel_types);
+
+                data.into_option(CommandOptionType::Channel)
+            }
+        }
+    };
+}
+
+channel_wrapper! {
+    /// A text channel.
+    ///
+    /// This type registers a `CHANNEL` option that only accepts text and
+    /// announcement channels. Other channels are rejected with an
+    /// [`InvalidChannelType`] error.
+    ///
+    /// The `channel_types` attribute can be used to restrict the accepted
+    /// channels further, for example to only accept announcement channels.
+    ///
+    /// ```
+    /// use twilight_interactions::command::{CommandModel, CreateCommand, TextChannel};
+    ///
+    /// #[derive(CommandModel, CreateCommand)]
+    /// #[command(name = "announce", desc = "Send an announcement")]
+    /// struct AnnounceCommand {
+    ///     /// Channel where the announcement is sent
+    ///     #[command(channel_types = "guild_announcement")]
+    ///     channel: TextChannel,
+    /// }
+    /// ```
+    ///
+    /// [`InvalidChannelType`]: ParseOptionErrorType::InvalidChannelType
+    TextChannel => [GuildText, GuildAnnouncement]
+}
+
+channel_wrapper! {
+    /// A voice channel.
+    ///
+    /// This type registers a `CHANNEL` option that only accepts voice and stage
+    /// channels. Other channels are rejected with an [`InvalidChannelType`]
+    /// error.
+    ///
+    /// [`InvalidChannelType`]: ParseOptionErrorType::InvalidChannelType
+    VoiceChannel => [GuildVoice, GuildStageVoice]
+}
+
+channel_wrapper! {
+    /// A thread.
+    ///
+    /// This type registers a `CHANNEL` option that only accepts public, private
+    /// and announcement threads. Other channels are rejected with an
+    /// [`InvalidChannelType`] error.
+    ///
+    /// [`InvalidChannelType`]: ParseOptionErrorType::InvalidChannelType
+    ThreadChannel => [PublicThread, PrivateThread, AnnouncementThread]
+}
+
+channel_wrapper! {
+    /// A forum channel.
+    ///
+    /// This type registers a `CHANNEL` option that only accepts forum and media
+    /// channels. Other channels are rejected with an [`InvalidChannelType`]
+    /// error.
+    ///
+    /// [`InvalidChannelType`]: ParseOptionErrorType::InvalidChannelType
+    ForumChannel => [GuildForum, GuildMedia]
+}
+
+channel_wrapper! {
+    /// A channel category.
+    ///
+    /// This type registers a `CHANNEL` option that only accepts categories.
+    /// Other channels are rejected with an [`InvalidChannelType`] error.
+    ///
+    /// [`InvalidChannelType`]: ParseOptionErrorType::InvalidChannelType
+    CategoryChannel => [GuildCategory]
+}
@@ -1,23 +1,31 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8},
+};
 
 use twilight_model::{
     application::{
-        command::CommandOptionValue as NumberCommandOptionValue,
+        command::{CommandOptionType, CommandOptionValue as NumberCommandOptionValue},
         interaction::{
             application_command::{CommandData, CommandDataOption, CommandOptionValue},
-            InteractionChannel, InteractionDataResolved, InteractionMember,
+            Interaction, InteractionChannel, InteractionDataResolved, InteractionMember,
         },
     },
     channel::Attachment,
     guild::Role,
     id::{
-        marker::{AttachmentMarker, ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        marker::{
+            AttachmentMarker, ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker,
+        },
         Id,
     },
     user::User,
 };
 
-use super::internal::CommandOptionData;
+use super::{
+    internal::{CommandOptionData, CreateOptionData, OptionSettings, SupportedSettings},
+    CreateOption,
+};
 use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 
 /// Parse command data into a concrete type.
@@ -49,13 +57,62 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// `max_value` for a field, this requirement will be checked when parsing
 /// command data.
 ///
-/// Not supporting additional validation is a design choice. This allows
-/// splitting validations that are ensured by Discord and those you perform
-/// on top of them. If an error occurs during parsing, it is always a bug, not
-/// a user mistake.
+/// Other validations can be performed with the `validate` attribute, which
+/// takes the path to a function called with a reference to the parsed value.
+/// The function must return a `Result<(), E>` where `E` implements
+/// [`Display`]. If the validation fails, the error message is returned in a
+/// [`ParseOptionErrorType::Custom`] error.
+///
+/// Errors returned by custom validators are user mistakes, unlike other parsing
+/// errors that are always a bug. You may want to handle them separately to
+/// reply with the error message.
+///
+/// ```
+/// use twilight_interactions::command::CommandModel;
 ///
-/// If you need to perform additional validation, consider creating another type
-/// that can be initialized from the command model.
+/// #[derive(CommandModel)]
+/// struct ColorCommand {
+///     #[command(validate = "validate_hex")]
+///     color: String,
+/// }
+///
+/// fn validate_hex(value: &str) -> Result<(), &'static str> {
+///     match u32::from_str_radix(value.trim_start_matches('#'), 16) {
+///         Ok(_) => Ok(()),
+///         Err(_) => Err("must be a valid hex color"),
+///     }
+/// }
+/// ```
+///
+/// ### Option relationships
+/// Discord does not support constraints between command options. The macro
+/// provides attributes to declare them, which are checked after all fields
+/// have been parsed:
+///
+/// - `requires`: the field can only be provided alongside the listed fields.
+/// - `conflicts_with`: the field cannot be provided alongside the listed fields.
+/// - `required_unless`: the field is required unless one of the listed fields
+///   is provided. The field must be an [`Option<T>`].
+/// - `one_of` (on the type): at least one of the listed fields must be
+///   provided. This attribute can be repeated to declare multiple groups.
+///
+/// Fields are referenced by their identifier, separated by spaces. Errors and
+/// the [`CONSTRAINTS`] metadata use the option names.
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, ResolvedUser};
+/// # use twilight_model::guild::Role;
+///
+/// #[derive(CommandModel)]
+/// #[command(one_of = "user role")]
+/// struct MuteCommand {
+///     user: Option<ResolvedUser>,
+///     role: Option<Role>,
+///     #[command(conflicts_with = "until")]
+///     duration: Option<i64>,
+///     until: Option<String>,
+/// }
+/// ```
 ///
 /// ### Autocomplete interactions
 /// Autocomplete interactions are supported with the `#[command(autocomplete = true)]`
@@ -68,7 +125,8 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 ///   there is no guarantee that a specific field has been filled before the
 ///   interaction is submitted.
 /// - If a field has autocomplete enabled, its type must be [`AutocompleteValue`]
-///   or the parsing will fail, since focused fields are sent as [`String`].
+///   or the parsing will fail, since focused fields are sent as a [`Partial`]
+///   value.
 /// - Autocomplete models are **partial**, which means that unknown fields
 ///   will not make the parsing fail.
 /// - It is not possible to derive [`CreateCommand`] on autocomplete models.
@@ -91,6 +149,40 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// }
 /// ```
 ///
+/// Instead of writing the autocomplete model by hand, it can be generated from
+/// the regular model with the `autocomplete_model` attribute. Fields with the
+/// `autocomplete` attribute become [`AutocompleteValue<T>`] and other fields
+/// become [`Option<T>`]. Field attributes are kept, except option
+/// relationships which are not checked in autocomplete models. The generated
+/// model implements [`Debug`], so the field types must implement it.
+///
+/// ```
+/// use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "edit", desc = "Edit a tag", autocomplete_model = "EditAutocomplete")]
+/// struct EditCommand {
+///     /// Name of the tag
+///     #[command(autocomplete = true)]
+///     name: String,
+///     /// New content of the tag
+///     content: String,
+/// }
+///
+/// // Generated autocomplete model
+/// fn focused_name(model: EditAutocomplete) -> Option<String> {
+///     match model.name {
+///         AutocompleteValue::Focused(partial) => Some(partial.into_string()),
+///         _ => None,
+///     }
+/// }
+/// ```
+///
+/// The [`FuzzyMatcher`] type can be used to rank a list of candidates against
+/// the value of the focused field and build the choices of the response.
+///
+/// [`FuzzyMatcher`]: super::FuzzyMatcher
+///
 /// ## Subcommands and subcommands groups
 /// This trait also supports parsing subcommands and subcommand groups when
 /// implemented on enums with all variants containing types that implement
@@ -131,17 +223,72 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// }
 /// ```
 ///
+/// Small subcommands can also be declared inline, as variants with named
+/// fields or unit variants. They are parsed and created like a command model
+/// with the same fields, and require a description (documentation comment or
+/// `desc` attribute on the variant).
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "config", desc = "Configure the bot")]
+/// enum ConfigCommand {
+///     /// Set the prefix
+///     #[command(name = "prefix")]
+///     Prefix {
+///         /// New prefix
+///         prefix: String,
+///     },
+///     /// Reset the configuration
+///     #[command(name = "reset")]
+///     Reset,
+/// }
+/// ```
+///
+/// Subcommand enums also support the `#[command(autocomplete = true)]`
+/// attribute, with autocomplete models as variants. The interaction is routed
+/// to the model of the subcommand containing the focused field, whose full
+/// path can be obtained with [`CommandInputData::focused_path`].
+///
+/// ```
+/// use twilight_interactions::command::{AutocompleteValue, CommandModel};
+///
+/// #[derive(CommandModel)]
+/// #[command(autocomplete = true)]
+/// struct TagEditAutocomplete {
+///     name: AutocompleteValue<String>,
+/// }
+///
+/// #[derive(CommandModel)]
+/// #[command(autocomplete = true)]
+/// enum TagAutocomplete {
+///     #[command(name = "edit")]
+///     Edit(TagEditAutocomplete),
+/// }
+/// ```
+///
 ///
 /// ## Macro attributes
 /// The macro provides a `#[command]` attribute to configure generated code.
 ///
-/// | Attribute                  | Type           | Location             | Description                                                     |
-/// |----------------------------|----------------|----------------------|-----------------------------------------------------------------|
-/// | `name`                     | `str`          | Variant (subcommand) | Subcommand name (required).                                     |
-/// | `rename`                   | `str`          | Field                | Use a different name for the field when parsing.                |
-/// | `channel_types`            | `str`          | Field                | Restricts the channel choice to specific types.[^channel_types] |
-/// | `max_value`, `min_value`   | `i64` or `f64` | Field                | Maximum and/or minimum value permitted.                         |
-/// | `max_length`, `min_length` | `u16`          | Field                | Maximum and/or minimum string length permitted.                 |
+/// | Attribute                  | Type             | Location             | Description                                                     |
+/// |----------------------------|------------------|----------------------|-----------------------------------------------------------------|
+/// | `name`                     | `str`            | Variant (subcommand) | Subcommand name (required).                                     |
+/// | `rename`                   | `str`            | Field                | Use a different name for the field when parsing.                |
+/// | `channel_types`            | `str`            | Field                | Restricts the channel choice to specific types.[^channel_types] |
+/// | `max_value`, `min_value`   | `i64` or `f64`   | Field                | Maximum and/or minimum value permitted.                         |
+/// | `max_length`, `min_length` | `u16`            | Field                | Maximum and/or minimum string length permitted.                 |
+/// | `formats`                  | `str`[^formats]  | Field                | Accepted formats of date and time fields.                       |
+/// | `separator`                | `str`            | Field                | Separator between items of list fields (default: `,`).          |
+/// | `max_items`, `min_items`   | `u16`            | Field                | Maximum and/or minimum number of items of list fields.          |
+/// | `repeat`                   | `u16`            | Field                | Number of options collected by `Vec` fields.[^repeat]           |
+/// | `same_guild`               | `bool`           | Field                | Only accept values from the current guild.[^same_guild]         |
+/// | `validate`                 | `fn`[^validate]  | Field                | Validate the parsed value with a custom function.               |
+/// | `requires`                 | `str`[^fields]   | Field                | Fields that must be provided alongside this field.              |
+/// | `conflicts_with`           | `str`[^fields]   | Field                | Fields that cannot be provided alongside this field.            |
+/// | `required_unless`          | `str`[^fields]   | Field                | Require this field unless one of the fields is provided.        |
+/// | `one_of`                   | `str`[^fields]   | Type                 | Require at least one of the fields to be provided.              |
 ///
 /// ### Example
 /// ```
@@ -156,20 +303,99 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// }
 /// ```
 ///
+/// The `formats`, `separator`, `max_items`, `min_items` and `same_guild`
+/// attributes are parsing settings read by some option types only:
+///
+/// - `formats` by date and time types (see [`DateTimeFormat`]).
+/// - `separator`, `max_items` and `min_items` by [`Vec<T>`] fields, or
+///   `min_items` by fields with the `repeat` attribute.
+/// - `same_guild` by [`MessageLink`].
+///
+/// These settings are forwarded to the inner type of [`Vec<T>`],
+/// [`AutocompleteValue<T>`] and transparent options. Using a setting on a
+/// field whose type does not read it is rejected at compile time.
+///
+/// ```compile_fail
+/// use twilight_interactions::command::CommandModel;
+///
+/// #[derive(CommandModel)]
+/// struct TagCommand {
+///     #[command(separator = ";")]
+///     tag: String,
+/// }
+/// ```
+///
 /// [^channel_types]: List of [`ChannelType`] names in snake_case separated by spaces
 ///                   like `guild_text private`.
 ///
+/// [^formats]: List of [`DateTimeFormat`] names in snake_case separated by
+///              spaces like `rfc3339 timestamp`.
+///
+/// [^validate]: Path to a function with a `fn(&T) -> Result<(), E>` signature,
+///              where `T` is the field type (without `Option`) and `E`
+///              implements [`Display`]. Deref coercion applies, so `&str` can
+///              be used for [`String`] fields.
+///
+/// [^fields]: List of field identifiers separated by spaces like `user role`.
+///
+/// [^same_guild]: Only supported by [`MessageLink`].
+///
+/// [^repeat]: The field is parsed from options numbered from 1 (like `file1`,
+///            `file2`, etc.) and contains the provided values in order. The
+///            first `min_items` options are required.
+///
 /// [`CreateCommand`]: super::CreateCommand
+/// [`CONSTRAINTS`]: Self::CONSTRAINTS
 /// [`ChannelType`]: twilight_model::channel::ChannelType
+/// [`DateTimeFormat`]: super::DateTimeFormat
+/// [`MessageLink`]: super::MessageLink
+/// [`AutocompleteValue<T>`]: AutocompleteValue
+/// [`Display`]: std::fmt::Display
+/// [`ParseOptionErrorType::Custom`]: crate::error::ParseOptionErrorType::Custom
 pub trait CommandModel: Sized {
+    /// Relationships between the command options.
+    ///
+    /// This metadata can be used to generate help text. It is empty unless
+    /// option relationships are declared with the derive macro.
+    const CONSTRAINTS: &'static [OptionConstraint] = &[];
+
     /// Construct this type from [`CommandInputData`].
     fn from_interaction(data: CommandInputData) -> Result<Self, ParseError>;
+
+    /// Construct this type from [`CommandInputData`] and the
+    /// [`CommandContext`] of the interaction.
+    ///
+    /// This method should be used instead of [`from_interaction`] when the
+    /// model has fields depending on the interaction, like [`MessageLink`]
+    /// fields with the `same_guild` attribute. The context is unknown when
+    /// parsing with [`from_interaction`]. The default implementation ignores
+    /// the context.
+    ///
+    /// [`from_interaction`]: Self::from_interaction
+    /// [`MessageLink`]: super::MessageLink
+    fn from_interaction_with(
+        data: CommandInputData,
+        context: &CommandContext,
+    ) -> Result<Self, ParseError> {
+        let _ = context;
+
+        Self::from_interaction(data)
+    }
 }
 
 impl<T: CommandModel> CommandModel for Box<T> {
+    const CONSTRAINTS: &'static [OptionConstraint] = T::CONSTRAINTS;
+
     fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
         T::from_interaction(data).map(Box::new)
     }
+
+    fn from_interaction_with(
+        data: CommandInputData,
+        context: &CommandContext,
+    ) -> Result<Self, ParseError> {
+        T::from_interaction_with(data, context).map(Box::new)
+    }
 }
 
 impl CommandModel for Vec<CommandDataOption> {
@@ -178,6 +404,37 @@ impl CommandModel for Vec<CommandDataOption> {
     }
 }
 
+/// Relationship between command options.
+///
+/// This type is used in [`CommandModel::CONSTRAINTS`]. Options are identified
+/// by their name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionConstraint {
+    /// The option can only be provided alongside another option.
+    Requires {
+        /// Name of the option.
+        field: &'static str,
+        /// Name of the required option.
+        requires: &'static str,
+    },
+    /// The option cannot be provided alongside another option.
+    ConflictsWith {
+        /// Name of the option.
+        field: &'static str,
+        /// Name of the conflicting option.
+        conflicts_with: &'static str,
+    },
+    /// The option is required unless one of the alternatives is provided.
+    RequiredUnless {
+        /// Name of the option.
+        field: &'static str,
+        /// Names of the alternative options.
+        alternatives: &'static [&'static str],
+    },
+    /// At least one of the options must be provided.
+    OneOf(&'static [&'static str]),
+}
+
 /// Parse command option into a concrete type.
 ///
 /// This trait is used by the implementation of [`CommandModel`] generated
@@ -186,22 +443,30 @@ impl CommandModel for Vec<CommandDataOption> {
 ///
 /// ## Option choices
 /// This trait can be derived on enums to represent command options with
-/// predefined choices. The `#[option]` attribute must be present on each
-/// variant.
+/// predefined choices. The `#[option]` attribute is used to set the name and
+/// value of each variant.
 ///
 /// The corresponding slash command types are automatically inferred from
 /// the `value` attribute. In the example below, the inferred type would
 /// be `INTEGER`.
 ///
-/// A `value` method is also generated for each variant to obtain the
-/// value of the variant. This method is not described in the trait
-/// as it is only implemented for option choices.
+/// A `value` method to obtain the value of the variant is also generated on
+/// the enum. It is not described in the trait as it is only implemented for
+/// option choices. The following items are generated as well if the enum has
+/// the `helpers` attribute:
+///
+/// - `name` and `localized_name` methods to obtain the (localized) name of
+///   the variant.
+/// - `ALL` constant with all the variants of the enum.
+/// - [`FromStr`] implementation parsing a choice from its value.
+/// - [`Display`] implementation displaying the name of the choice.
 ///
 /// ### Example
 /// ```
 /// use twilight_interactions::command::CommandOption;
 ///
-/// #[derive(CommandOption)]
+/// #[derive(CommandOption, Debug, PartialEq)]
+/// #[option(helpers)]
 /// enum TimeUnit {
 ///     #[option(name = "Minute", value = 60)]
 ///     Minute,
@@ -212,16 +477,170 @@ impl CommandModel for Vec<CommandDataOption> {
 /// }
 ///
 /// assert_eq!(TimeUnit::Minute.value(), 60);
+/// assert_eq!(TimeUnit::Hour.name(), "Hour");
+/// assert_eq!(TimeUnit::ALL.len(), 3);
+/// assert_eq!("86400".parse().ok(), Some(TimeUnit::Day));
 /// ```
 ///
 /// ### Macro attributes
 /// The macro provides an `#[option]` attribute to configure the generated code.
 ///
-/// | Attribute | Type                  | Location | Description                                |
-/// |-----------|-----------------------|----------|--------------------------------------------|
-/// | `name`    | `str`                 | Variant  | Set the name of the command option choice. |
-/// | `value`   | `str`, `i64` or `f64` | Variant  | Value of the command option choice.        |
+/// | Attribute      | Type                  | Location | Description                                        |
+/// |----------------|-----------------------|----------|----------------------------------------------------|
+/// | `name`         | `str`                 | Variant  | Set the name of the command option choice.         |
+/// | `value`        | `str`, `i64` or `f64` | Variant  | Value of the command option choice.                |
+/// | `rename_all`   | `str`[^rename_all]    | Type     | Infer string values from the variant identifiers.  |
+/// | `int`          | flag                  | Type     | Infer integer values by numbering variants from 0. |
+/// | `other`        | flag                  | Variant  | Capture unknown values in this variant.            |
+/// | `autocomplete` | flag                  | Type     | Provide choices with autocomplete.                 |
+/// | `helpers`      | flag                  | Type     | Generate the choice helpers listed above.          |
+///
+/// If the `name` attribute is not set, the name is taken from the variant
+/// documentation comment, or from the variant identifier converted to title
+/// case (`NorthAmerica` becomes `North America`). Values can be inferred with
+/// the `rename_all` or `int` attribute on the enum, and overridden with the
+/// `value` attribute. Inferred integer values continue from the previous
+/// variant value, like enum discriminants.
+///
+/// ```
+/// use twilight_interactions::command::CommandOption;
+///
+/// #[derive(CommandOption)]
+/// #[option(rename_all = "snake_case")]
+/// enum Region {
+///     /// North America (US, Canada)
+///     NorthAmerica,
+///     EuropeWest,
+///     #[option(value = "apac")]
+///     AsiaPacific,
+/// }
+///
+/// assert_eq!(Region::EuropeWest.value(), "europe_west");
+/// ```
+///
+/// Choices with the same value are rejected at compile time, including
+/// inferred values colliding with other variants.
 ///
+/// ```compile_fail
+/// use twilight_interactions::command::CommandOption;
+///
+/// #[derive(CommandOption)]
+/// #[option(int)]
+/// enum Priority {
+///     Low,
+///     #[option(value = 0)]
+///     High,
+/// }
+/// ```
+///
+/// A single tuple variant with the `other` attribute can be used to capture
+/// unknown values instead of returning an [`InvalidChoice`] error, for example
+/// values of removed choices sent by outdated clients. The variant field must
+/// be a [`String`], [`i64`] or [`f64`] depending on the choice type, and the
+/// variant is not registered as a choice. The captured value is returned by the
+/// `value` method, which borrows the value of string choices, and by the
+/// generated `other_value` method, which returns [`None`] for other variants.
+///
+/// ```
+/// use twilight_interactions::command::CommandOption;
+///
+/// #[derive(CommandOption)]
+/// enum Animal {
+///     #[option(name = "Dog", value = "dog")]
+///     Dog,
+///     #[option(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Animal::Other("cat".into()).value(), "cat");
+/// assert_eq!(Animal::Other("cat".into()).other_value(), Some("cat"));
+/// assert_eq!(Animal::Dog.other_value(), None);
+/// ```
+///
+/// Enums with the `autocomplete` attribute are registered as autocomplete
+/// options without static choices. An `autocomplete` method is generated to
+/// get the choices matching the user input with a [`FuzzyMatcher`], which can
+/// be sent in response to the autocomplete interaction. The locale of the user
+/// can be given to match the localized names of the choices. Parsing the option
+/// is unchanged.
+///
+/// ```
+/// use twilight_interactions::command::CommandOption;
+///
+/// #[derive(CommandOption)]
+/// #[option(rename_all = "lowercase", autocomplete)]
+/// enum Language {
+///     English,
+///     French,
+///     German,
+/// }
+///
+/// let choices = Language::autocomplete("fre", Some("en-US"));
+/// assert_eq!(choices[0].name, "French");
+/// ```
+///
+/// Discord limits static choices to 25 per option, so enums with more variants
+/// must have the `autocomplete` attribute.
+///
+/// ```compile_fail
+/// use twilight_interactions::command::CommandOption;
+///
+/// #[derive(CommandOption)]
+/// #[option(int)]
+/// enum Number {
+///     N0, N1, N2, N3, N4, N5, N6, N7, N8, N9, N10, N11, N12,
+///     N13, N14, N15, N16, N17, N18, N19, N20, N21, N22, N23, N24, N25,
+/// }
+/// ```
+///
+/// [`InvalidChoice`]: ParseOptionErrorType::InvalidChoice
+/// [`FuzzyMatcher`]: super::FuzzyMatcher
+/// [`FromStr`]: std::str::FromStr
+/// [`Display`]: std::fmt::Display
+/// [^rename_all]: One of `lowercase`, `UPPERCASE`, `snake_case`,
+///                `SCREAMING_SNAKE_CASE`, `kebab-case`, `camelCase` or
+///                `PascalCase`.
+///
+/// ## Transparent options
+/// This trait can also be derived on tuple structs with a single field and the
+/// `#[option(transparent)]` attribute. Parsing is delegated to the inner type,
+/// which allows to use domain types like `Username(String)` as fields.
+///
+/// Constraints (like `max_length` or `min_value`) can be declared on the
+/// struct and are used as the [default data](Self::default_data) of the
+/// option. A `validate` function can also be provided to check the parsed
+/// value, with the same signature as the field attribute of [`CommandModel`].
+///
+/// ```
+/// use twilight_interactions::command::{CommandOption, CreateOption};
+///
+/// #[derive(CommandOption, CreateOption)]
+/// #[option(transparent, min_value = 0, max_value = 100, desc = "A percentage")]
+/// struct Percentage(i64);
+///
+/// #[derive(CommandOption, CreateOption)]
+/// #[option(transparent, max_length = 32, validate = "validate_username")]
+/// struct Username(String);
+///
+/// fn validate_username(value: &str) -> Result<(), &'static str> {
+///     if value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+///         Ok(())
+///     } else {
+///         Err("invalid username")
+///     }
+/// }
+/// ```
+///
+/// | Attribute                | Type                | Description                                           |
+/// |--------------------------|---------------------|-------------------------------------------------------|
+/// | `transparent`            | flag                | Delegate to the inner type (required).                |
+/// | `desc`                   | `str`               | Default description of the option.[^default_desc]     |
+/// | `desc_localizations`     | `fn`                | Default localized description of the option.          |
+/// | `validate`               | `fn`                | Function used to validate the parsed value.           |
+/// | `channel_types`, etc.    | Same as fields      | Default constraints, overridden by field attributes.  |
+///
+/// [^default_desc]: Used by fields without documentation comment or `desc`
+///                  attribute in [`CreateCommand`](super::CreateCommand).
 pub trait CommandOption: Sized {
     /// Convert a [`CommandOptionValue`] into this value.
     fn from_option(
@@ -229,6 +648,81 @@ pub trait CommandOption: Sized {
         data: CommandOptionData,
         resolved: Option<&InteractionDataResolved>,
     ) -> Result<Self, ParseOptionErrorType>;
+
+    /// Parsing settings read by this type.
+    ///
+    /// Field attributes setting a parsing setting not read by the type, like
+    /// `separator` on a [`String`] field, are rejected at compile time by the
+    /// derive macro. Types reading settings in [`from_option_with`] should
+    /// list them here, and types wrapping another option type should include
+    /// the settings of the inner type.
+    ///
+    /// [`from_option_with`]: Self::from_option_with
+    const SETTINGS: SupportedSettings = SupportedSettings::NONE;
+
+    /// Convert a [`CommandOptionValue`] into this value, with the parsing
+    /// settings of the field.
+    ///
+    /// This method is called instead of [`from_option`] when parsing fields
+    /// with attributes only used when parsing, like `formats`. The default
+    /// implementation ignores the settings. Types wrapping another option type
+    /// should forward the settings to the inner type.
+    ///
+    /// [`from_option`]: Self::from_option
+    fn from_option_with(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        settings: &OptionSettings,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let _ = settings;
+
+        Self::from_option(value, data, resolved)
+    }
+
+    /// Default data of the option.
+    ///
+    /// This method can be overridden to attach default constraints (like
+    /// `max_length` or `channel_types`) to every field of this type, which is
+    /// useful for newtypes reused across many commands. Values set with field
+    /// attributes take precedence over the values returned by this method.
+    ///
+    /// The [`CreateOption`] trait has a similar method used when registering
+    /// the option.
+    ///
+    /// ```
+    /// use twilight_interactions::command::{
+    ///     internal::CommandOptionData, CommandOption,
+    /// };
+    /// # use twilight_interactions::error::ParseOptionErrorType;
+    /// # use twilight_model::application::interaction::{
+    /// #     application_command::CommandOptionValue, InteractionDataResolved,
+    /// # };
+    ///
+    /// struct Reason(String);
+    ///
+    /// impl CommandOption for Reason {
+    ///     fn from_option(
+    ///         value: CommandOptionValue,
+    ///         data: CommandOptionData,
+    ///         resolved: Option<&InteractionDataResolved>,
+    ///     ) -> Result<Self, ParseOptionErrorType> {
+    ///         String::from_option(value, data, resolved).map(Self)
+    ///     }
+    ///
+    ///     fn default_data() -> CommandOptionData {
+    ///         CommandOptionData {
+    ///             max_length: Some(512),
+    ///             ..Default::default()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`CreateOption`]: super::CreateOption
+    fn default_data() -> CommandOptionData {
+        CommandOptionData::default()
+    }
 }
 
 /// Data sent by Discord when receiving a command.
@@ -320,6 +814,57 @@ impl<'a> CommandInputData<'a> {
             .map(|option| &*option.name)
     }
 
+    /// Get the full path of the focused field.
+    ///
+    /// Unlike [`focused`], this method also searches in subcommands and
+    /// subcommand groups. The path contains the names of the subcommand group
+    /// and subcommand (if any), followed by the name of the focused field.
+    ///
+    /// ### Example
+    /// ```
+    /// use twilight_interactions::command::CommandInputData;
+    /// # use twilight_model::application::{
+    /// #   interaction::application_command::{CommandDataOption, CommandOptionValue},
+    /// #   command::CommandOptionType,
+    /// # };
+    /// #
+    /// # let focused = CommandDataOption { name: "name".into(), value: CommandOptionValue::Focused("fa".into(), CommandOptionType::String) };
+    /// # let options = vec![CommandDataOption { name: "edit".into(), value: CommandOptionValue::SubCommand(vec![focused]) }];
+    ///
+    /// // `options` is a Vec<CommandDataOption> with an `edit` subcommand
+    /// let data = CommandInputData { options, resolved: None };
+    ///
+    /// assert_eq!(data.focused_path(), Some(vec!["edit", "name"]));
+    /// ```
+    ///
+    /// [`focused`]: Self::focused
+    pub fn focused_path(&self) -> Option<Vec<&str>> {
+        fn find<'a>(options: &'a [CommandDataOption], path: &mut Vec<&'a str>) -> bool {
+            for option in options {
+                match &option.value {
+                    CommandOptionValue::Focused(_, _) => {
+                        path.push(&option.name);
+                        return true;
+                    }
+                    CommandOptionValue::SubCommand(options)
+                    | CommandOptionValue::SubCommandGroup(options) => {
+                        path.push(&option.name);
+                        if find(options, path) {
+                            return true;
+                        }
+                        path.pop();
+                    }
+                    _ => {}
+                }
+            }
+
+            false
+        }
+
+        let mut path = Vec::new();
+        find(&self.options, &mut path).then_some(path)
+    }
+
     /// Parse a subcommand's [`CommandOptionValue`].
     ///
     /// This method's signature is the same as the [`CommandOption`] trait,
@@ -351,6 +896,43 @@ impl From<CommandData> for CommandInputData<'_> {
     }
 }
 
+/// Context of the interaction a command is received in.
+///
+/// This type is used in [`CommandModel::from_interaction_with`] to parse
+/// fields depending on the interaction. It can be initialized from an
+/// [`Interaction`] using the [From] trait.
+///
+/// The [`Default`] context is unknown, and is used when parsing with
+/// [`CommandModel::from_interaction`]. Checks depending on the context, like
+/// the `same_guild` attribute, are skipped with an unknown context.
+///
+/// [`Interaction`]: twilight_model::application::interaction::Interaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CommandContext {
+    /// ID of the guild the interaction was invoked in.
+    ///
+    /// This is `Some(None)` for interactions invoked outside of a guild, and
+    /// [`None`] if the context is unknown.
+    pub guild_id: Option<Option<Id<GuildMarker>>>,
+}
+
+impl CommandContext {
+    /// Create a new [`CommandContext`] from the ID of the guild the
+    /// interaction was invoked in, [`None`] outside of a guild.
+    pub const fn new(guild_id: Option<Id<GuildMarker>>) -> Self {
+        Self {
+            guild_id: Some(guild_id),
+        }
+    }
+}
+
+impl From<&Interaction> for CommandContext {
+    fn from(interaction: &Interaction) -> Self {
+        Self::new(interaction.guild_id)
+    }
+}
+
 /// A resolved Discord user.
 ///
 /// This struct implements [`CommandOption`] and can be used to
@@ -401,11 +983,92 @@ pub enum AutocompleteValue<T> {
     /// The field has not been completed yet.
     None,
     /// The field is focused by the user and being completed.
-    Focused(String),
+    Focused(Partial),
     /// The field has been completed by the user.
     Completed(T),
 }
 
+/// Partial value of a focused autocomplete field.
+///
+/// Discord sends the value of the focused field as a string, along with the
+/// type of the option. The raw value is kept as-is, and typed accessors parse
+/// it on a best-effort basis, returning [`None`] if the user input is not a
+/// valid value yet (like `-` for an `INTEGER` option).
+///
+/// ```
+/// use twilight_interactions::command::{AutocompleteValue, CommandModel};
+///
+/// #[derive(CommandModel)]
+/// #[command(autocomplete = true)]
+/// struct TicketCommand {
+///     number: AutocompleteValue<i64>,
+/// }
+///
+/// fn suggest(command: TicketCommand) -> Vec<i64> {
+///     match command.number {
+///         AutocompleteValue::Focused(partial) => match partial.as_i64() {
+///             Some(number) => (0..5).map(|i| number * 10 + i).collect(),
+///             None => Vec::new(),
+///         },
+///         _ => Vec::new(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partial {
+    value: String,
+    kind: CommandOptionType,
+}
+
+impl Partial {
+    /// Create a new [`Partial`] from the raw value of the given option type.
+    pub const fn new(value: String, kind: CommandOptionType) -> Self {
+        Self { value, kind }
+    }
+
+    /// Type of the focused option.
+    pub const fn kind(&self) -> CommandOptionType {
+        self.kind
+    }
+
+    /// Get the raw value, as sent by Discord.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Parse the value of an `INTEGER` option.
+    ///
+    /// Returns [`None`] if the option is not an `INTEGER` option or if the
+    /// value is not a valid integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.kind {
+            CommandOptionType::Integer => self.value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Parse the value of a `NUMBER` option.
+    ///
+    /// Returns [`None`] if the option is not a `NUMBER` option or if the
+    /// value is not a valid finite number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.kind {
+            CommandOptionType::Number => self
+                .value
+                .trim()
+                .parse()
+                .ok()
+                .filter(|value: &f64| value.is_finite()),
+            _ => None,
+        }
+    }
+
+    /// Consume the partial value and return the raw value.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+}
+
 macro_rules! lookup {
     ($resolved:ident.$cat:ident, $id:expr) => {
         $resolved
@@ -428,20 +1091,37 @@ impl<T> CommandOption for AutocompleteValue<T>
 where
     T: CommandOption,
 {
+    const SETTINGS: SupportedSettings = T::SETTINGS;
+
     fn from_option(
         value: CommandOptionValue,
         data: CommandOptionData,
         resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        Self::from_option_with(value, data, &OptionSettings::default(), resolved)
+    }
+
+    fn from_option_with(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        settings: &OptionSettings,
+        resolved: Option<&InteractionDataResolved>,
     ) -> Result<Self, ParseOptionErrorType> {
         match value {
-            CommandOptionValue::Focused(value, _) => Ok(Self::Focused(value)),
+            CommandOptionValue::Focused(value, kind) => {
+                Ok(Self::Focused(Partial::new(value, kind)))
+            }
             other => {
-                let parsed = T::from_option(other, data, resolved)?;
+                let parsed = T::from_option_with(other, data, settings, resolved)?;
 
                 Ok(Self::Completed(parsed))
             }
         }
     }
+
+    fn default_data() -> CommandOptionData {
+        T::default_data()
+    }
 }
 
 impl CommandOption for String {
@@ -483,6 +1163,111 @@ impl CommandOption for Cow<'_, str> {
     }
 }
 
+impl<T: CommandOption + CreateOption> CommandOption for Vec<T> {
+    const SETTINGS: SupportedSettings = T::SETTINGS
+        .union(SupportedSettings::SEPARATOR)
+        .union(SupportedSettings::ITEMS);
+
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        Self::from_option_with(value, data, &OptionSettings::default(), resolved)
+    }
+
+    fn from_option_with(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        settings: &OptionSettings,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = match value {
+            CommandOptionValue::String(value) => value,
+            other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
+        };
+
+        let kind = list_item_type::<T>();
+        let items = value
+            .split(settings.separator.as_deref().unwrap_or(","))
+            .map(str::trim)
+            .enumerate()
+            .filter(|(_, item)| !item.is_empty())
+            .map(|(index, item)| {
+                list_item(item, kind)
+                    .and_then(|item| T::from_option_with(item, data.clone(), settings, resolved))
+                    .map_err(|kind| ParseOptionErrorType::InvalidItem {
+                        index,
+                        kind: Box::new(kind),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if settings
+            .min_items
+            .is_some_and(|min| items.len() < usize::from(min))
+            || settings
+                .max_items
+                .is_some_and(|max| items.len() > usize::from(max))
+        {
+            return Err(ParseOptionErrorType::ItemCountOutOfRange(items.len()));
+        }
+
+        Ok(items)
+    }
+
+    fn default_data() -> CommandOptionData {
+        <T as CommandOption>::default_data()
+    }
+}
+
+/// Option type of the items of a list option.
+fn list_item_type<T: CreateOption>() -> CommandOptionType {
+    let data = CreateOptionData {
+        name: String::new(),
+        name_localizations: None,
+        description: String::new(),
+        description_localizations: None,
+        required: None,
+        autocomplete: false,
+        data: T::default_data(),
+    };
+
+    T::create_option(data).kind
+}
+
+/// Convert an item of a list option into a value of the item option type.
+///
+/// Only `STRING`, `INTEGER`, `NUMBER` and `BOOLEAN` items can be parsed from
+/// a string.
+fn list_item(
+    item: &str,
+    kind: CommandOptionType,
+) -> Result<CommandOptionValue, ParseOptionErrorType> {
+    let value = match kind {
+        CommandOptionType::String => return Ok(CommandOptionValue::String(item.to_owned())),
+        CommandOptionType::Integer => item
+            .parse()
+            .map(CommandOptionValue::Integer)
+            .map_err(|error| error.to_string()),
+        CommandOptionType::Number => match item.parse::<f64>() {
+            Ok(value) if !value.is_finite() => {
+                return Err(ParseOptionErrorType::NumberOutOfRange(value))
+            }
+            value => value
+                .map(CommandOptionValue::Number)
+                .map_err(|error| error.to_string()),
+        },
+        CommandOptionType::Boolean => item
+            .parse()
+            .map(CommandOptionValue::Boolean)
+            .map_err(|error| error.to_string()),
+        _ => return Err(ParseOptionErrorType::InvalidType(CommandOptionType::String)),
+    };
+
+    value.map_err(ParseOptionErrorType::ParseFailed)
+}
+
 impl CommandOption for i64 {
     fn from_option(
         value: CommandOptionValue,
@@ -537,6 +1322,66 @@ impl CommandOption for f64 {
     }
 }
 
+macro_rules! impl_integer_option {
+    ($($ty:ty),*) => {
+        $(
+            impl CommandOption for $ty {
+                fn from_option(
+                    value: CommandOptionValue,
+                    data: CommandOptionData,
+                    resolved: Option<&InteractionDataResolved>,
+                ) -> Result<Self, ParseOptionErrorType> {
+                    let value = i64::from_option(value, data, resolved)?;
+
+                    <$ty>::try_from(value).map_err(|_| ParseOptionErrorType::IntegerOutOfRange(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_option!(i8, i16, i32, u8, u16, u32, u64, usize);
+
+macro_rules! impl_non_zero_option {
+    ($($ty:ty => $inner:ty),*) => {
+        $(
+            impl CommandOption for $ty {
+                fn from_option(
+                    value: CommandOptionValue,
+                    data: CommandOptionData,
+                    resolved: Option<&InteractionDataResolved>,
+                ) -> Result<Self, ParseOptionErrorType> {
+                    let value = i64::from_option(value, data, resolved)?;
+
+                    <$inner>::try_from(value)
+                        .ok()
+                        .and_then(<$ty>::new)
+                        .ok_or(ParseOptionErrorType::IntegerOutOfRange(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_option!(NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64);
+
+impl CommandOption for f32 {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = f64::from_option(value, data, resolved)?;
+        let converted = value as f32;
+
+        if converted.is_finite() {
+            Ok(converted)
+        } else {
+            Err(ParseOptionErrorType::NumberOutOfRange(value))
+        }
+    }
+}
+
 impl CommandOption for bool {
     fn from_option(
         value: CommandOptionValue,
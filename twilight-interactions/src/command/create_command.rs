@@ -1,8 +1,13 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Display,
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8},
+};
 
 use twilight_model::{
     application::{
-        command::{Command, CommandOption, CommandOptionType, CommandType},
+        command::{Command, CommandOption, CommandOptionType, CommandOptionValue, CommandType},
         interaction::{InteractionChannel, InteractionContextType},
     },
     channel::Attachment,
@@ -15,7 +20,10 @@ use twilight_model::{
     user::User,
 };
 
-use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    ResolvedMentionable, ResolvedUser,
+};
 
 /// Create a slash command from a type.
 ///
@@ -30,7 +38,63 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// Unlike the [`CommandModel`] trait, all fields or variants of the type it's
 /// implemented on must have a description. The description corresponds either
 /// to the first line of the documentation comment or the value of the `desc`
-/// attribute. The type must also be named with the `name` attribute.
+/// attribute, and defaults to the description of the field type (see below).
+/// The type must also be named with the `name` attribute.
+///
+/// Variants wrapping another command are registered with the name of the
+/// variant. Their description and localizations default to the ones of the
+/// inner command, and can be overridden with a documentation comment or the
+/// `desc`, `desc_localizations` and `name_localizations` attributes on the
+/// variant. Localized names of the inner command are dropped if the variant
+/// has another name. Subcommand groups cannot contain other subcommand groups, which is
+/// checked at compile time:
+///
+/// ```compile_fail
+/// use twilight_interactions::command::{CommandModel, CreateCommand};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "add", desc = "Add a tag")]
+/// struct AddCommand;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "tag", desc = "Manage tags")]
+/// enum TagCommand {
+///     #[command(name = "add")]
+///     Add(AddCommand),
+/// }
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "admin", desc = "Administration")]
+/// enum AdminCommand {
+///     #[command(name = "settings")]
+///     Settings(SettingsCommand),
+/// }
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "settings", desc = "Manage settings")]
+/// enum SettingsCommand {
+///     #[command(name = "tag")]
+///     Tag(TagCommand),
+/// }
+/// ```
+///
+/// Fields without documentation comment or `desc` attribute use the default
+/// description of their type (see [`CreateOption::default_description`]), and
+/// constraints like `max_length` default to the values returned by
+/// [`CreateOption::default_data`]. The generated [`create_command`] method
+/// panics if the type of such a field has no default description:
+///
+/// ```should_panic
+/// use twilight_interactions::command::CreateCommand;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "echo", desc = "Echo a message")]
+/// struct EchoCommand {
+///     text: String,
+/// }
+///
+/// EchoCommand::create_command();
+/// ```
 ///
 /// ## Example
 /// ```
@@ -73,6 +137,7 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// | `channel_types`            | `str`               | Field                  | Restricts the channel choice to specific types.[^channel_types]           |
 /// | `max_value`, `min_value`   | `i64` or `f64`      | Field                  | Set the maximum and/or minimum value permitted.                           |
 /// | `max_length`, `min_length` | `u16`               | Field                  | Maximum and/or minimum string length permitted.                           |
+/// | `repeat`, `min_items`      | `u16`               | Field                  | Register numbered options for `Vec` fields.[^repeat]                      |
 /// | `contexts`                 | `str`               | Type                   | Interaction context(s) where the command can be used.[^contexts]          |
 /// | `integration_types`        | `str`               | Type                   | Installation contexts where the command is available.[^integration_types] |
 ///
@@ -86,6 +151,9 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// [^channel_types]: List of [`ChannelType`] names in snake_case separated by spaces
 /// like `guild_text private`.
 ///
+/// [^repeat]: Register `repeat` options numbered from 1 (like `file1`, `file2`,
+/// etc.), the first `min_items` being required.
+///
 /// [^contexts]: List of [`InteractionContextType`] names in snake_case separated by
 /// spaces like `guild private_channel`.
 ///
@@ -96,16 +164,25 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// [`ChannelType`]: twilight_model::channel::ChannelType
 /// [`InteractionContextType`]: twilight_model::application::interaction::InteractionContextType
 /// [`ApplicationIntegrationType`]: twilight_model::oauth::ApplicationIntegrationType
+/// [`create_command`]: Self::create_command
 pub trait CreateCommand: Sized {
     /// Name of the command.
     const NAME: &'static str;
 
+    /// Number of nested subcommand levels.
+    ///
+    /// This is set by the derive macro to check the nesting of subcommand
+    /// groups at compile time.
+    #[doc(hidden)]
+    const NESTING_DEPTH: usize = 0;
+
     /// Create an [`ApplicationCommandData`] for this type.
     fn create_command() -> ApplicationCommandData;
 }
 
 impl<T: CreateCommand> CreateCommand for Box<T> {
     const NAME: &'static str = T::NAME;
+    const NESTING_DEPTH: usize = T::NESTING_DEPTH;
 
     fn create_command() -> ApplicationCommandData {
         T::create_command()
@@ -141,18 +218,49 @@ impl<T: CreateCommand> CreateCommand for Box<T> {
 /// ### Macro attributes
 /// The macro provides an `#[option]` attribute to configure the generated code.
 ///
-/// | Attribute            | Type                  | Location | Description                                  |
-/// |----------------------|-----------------------|----------|----------------------------------------------|
-/// | `name`               | `str`                 | Variant  | Set the name of the command option choice.   |
-/// | `name_localizations` | `fn`[^localization]   | Variant  | Localized name of the command option choice. |
-/// | `value`              | `str`, `i64` or `f64` | Variant  | Value of the command option choice.          |
+/// | Attribute            | Type                  | Location | Description                                        |
+/// |----------------------|-----------------------|----------|----------------------------------------------------|
+/// | `name`               | `str`                 | Variant  | Set the name of the command option choice.         |
+/// | `name_localizations` | `fn`[^localization]   | Variant  | Localized name of the command option choice.       |
+/// | `value`              | `str`, `i64` or `f64` | Variant  | Value of the command option choice.                |
+/// | `rename_all`         | `str`                 | Type     | Infer string values from the variant identifiers.  |
+/// | `int`                | flag                  | Type     | Infer integer values by numbering variants from 0. |
+/// | `other`              | flag                  | Variant  | Capture unknown values (not registered).           |
+///
+/// See the [`CommandOption`](super::CommandOption#macro-attributes)
+/// documentation for more information about inferred names and values.
 ///
 /// [^localization]: Path to a function that returns a type that implements
 ///                  `IntoIterator<Item = (ToString, ToString)>`. See the
 ///                  [module documentation](crate::command) to learn more.
+///
+/// ## Transparent options
+/// This trait can also be derived on tuple structs with a single field and the
+/// `#[option(transparent)]` attribute. See the [`CommandOption`
+/// documentation](super::CommandOption#transparent-options) for more
+/// information.
 pub trait CreateOption: Sized {
     /// Create a [`CommandOption`] from this type.
     fn create_option(data: CreateOptionData) -> CommandOption;
+
+    /// Default data of the option.
+    ///
+    /// Values set with field attributes take precedence over the values
+    /// returned by this method. See [`CommandOption::default_data`].
+    ///
+    /// [`CommandOption::default_data`]: super::CommandOption::default_data
+    fn default_data() -> CommandOptionData {
+        CommandOptionData::default()
+    }
+
+    /// Default description of the option.
+    ///
+    /// This description is used for fields without documentation comment or
+    /// `desc` attribute. The generated [`CreateCommand`] implementation
+    /// panics if this method returns [`None`] for such fields.
+    fn default_description() -> Option<DescLocalizations> {
+        None
+    }
 }
 
 /// Localization data for command names.
@@ -317,6 +425,48 @@ impl CreateOption for Cow<'_, str> {
     }
 }
 
+impl<T: CreateOption> CreateOption for Vec<T> {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        let autocomplete = data.autocomplete;
+        let item = T::create_option(data);
+
+        assert!(
+            matches!(
+                item.kind,
+                CommandOptionType::String
+                    | CommandOptionType::Integer
+                    | CommandOptionType::Number
+                    | CommandOptionType::Boolean
+            ),
+            "list option `{}` has items of type {}, only STRING, INTEGER, NUMBER and BOOLEAN items are supported",
+            item.name,
+            item.kind.kind()
+        );
+
+        // Constraints and choices apply to each item and are checked when
+        // parsing.
+        CommandOption {
+            autocomplete: Some(autocomplete),
+            channel_types: None,
+            choices: None,
+            kind: CommandOptionType::String,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            ..item
+        }
+    }
+
+    fn default_data() -> CommandOptionData {
+        T::default_data()
+    }
+
+    fn default_description() -> Option<DescLocalizations> {
+        T::default_description()
+    }
+}
+
 impl CreateOption for i64 {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Integer)
@@ -329,6 +479,92 @@ impl CreateOption for f64 {
     }
 }
 
+impl CreateOption for f32 {
+    fn create_option(mut data: CreateOptionData) -> CommandOption {
+        let max = f64::from(f32::MAX);
+        let bound = |value| match value {
+            Some(CommandOptionValue::Integer(value)) => Some(value as f64),
+            Some(CommandOptionValue::Number(value)) => Some(value),
+            _ => None,
+        };
+
+        let min_value = bound(data.data.min_value).map_or(-max, |value| value.max(-max));
+        let max_value = bound(data.data.max_value).map_or(max, |value| value.min(max));
+        check_bounds(&data.name, min_value, max_value, -max, max);
+
+        data.data.min_value = Some(CommandOptionValue::Number(min_value));
+        data.data.max_value = Some(CommandOptionValue::Number(max_value));
+
+        data.into_option(CommandOptionType::Number)
+    }
+}
+
+/// Largest integer that can be sent by Discord (2^53).
+const MAX_SAFE_INTEGER: i128 = 1 << 53;
+
+/// Create an `INTEGER` option restricted to the range of an integer type.
+///
+/// Bounds set by the user are kept if they are more restrictive than the type
+/// limits.
+///
+/// # Panics
+/// Panics if the bounds set by the user leave no value in the range of the
+/// type, like a `min_value` of 300 on a `u8` option.
+fn integer_option(mut data: CreateOptionData, min: i128, max: i128) -> CommandOption {
+    let bound = |value| match value {
+        Some(CommandOptionValue::Integer(value)) => Some(i128::from(value)),
+        _ => None,
+    };
+
+    let min_value = bound(data.data.min_value).map_or(min, |value| value.max(min));
+    let max_value = bound(data.data.max_value).map_or(max, |value| value.min(max));
+    check_bounds(&data.name, min_value, max_value, min, max);
+
+    let min_value = min_value.max(-MAX_SAFE_INTEGER) as i64;
+    let max_value = max_value.min(MAX_SAFE_INTEGER) as i64;
+
+    data.data.min_value = Some(CommandOptionValue::Integer(min_value));
+    data.data.max_value = Some(CommandOptionValue::Integer(max_value));
+
+    data.into_option(CommandOptionType::Integer)
+}
+
+/// Ensure the bounds of an option merged with the limits of its type are not
+/// inverted.
+fn check_bounds<T: PartialOrd + Display>(name: &str, min_value: T, max_value: T, min: T, max: T) {
+    assert!(
+        min_value <= max_value,
+        "`min_value` and `max_value` of option `{name}` leave no value in the range of its type ({min} to {max})"
+    );
+}
+
+macro_rules! impl_integer_create_option {
+    ($($ty:ty => $min:expr, $max:expr);*) => {
+        $(
+            impl CreateOption for $ty {
+                fn create_option(data: CreateOptionData) -> CommandOption {
+                    integer_option(data, $min as i128, $max as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_create_option!(
+    i8 => i8::MIN, i8::MAX;
+    i16 => i16::MIN, i16::MAX;
+    i32 => i32::MIN, i32::MAX;
+    u8 => u8::MIN, u8::MAX;
+    u16 => u16::MIN, u16::MAX;
+    u32 => u32::MIN, u32::MAX;
+    u64 => u64::MIN, u64::MAX;
+    usize => usize::MIN, usize::MAX;
+    NonZeroU8 => 1, u8::MAX;
+    NonZeroU16 => 1, u16::MAX;
+    NonZeroU32 => 1, u32::MAX;
+    NonZeroU64 => 1, u64::MAX
+);
+
 impl CreateOption for bool {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Boolean)
@@ -0,0 +1,244 @@
+//! Date and time option types.
+//!
+//! Implementations for [`chrono`] and [`time`] types are available behind the
+//! `chrono` and `time` features.
+//!
+//! [`chrono`]: https://docs.rs/chrono
+//! [`time`]: https://docs.rs/time
+
+/// Date and time format accepted by date and time option types.
+///
+/// Formats can be restricted with the `formats` attribute, which takes a list
+/// of formats in snake_case separated by spaces like `rfc3339 timestamp`. All
+/// formats are accepted by default.
+///
+/// Dates and times without offset are interpreted as UTC. Dates parsed from
+/// a value with an offset are taken in this offset, so
+/// `2024-05-01T00:30:00+02:00` is parsed as the `2024-05-01` date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeFormat {
+    /// [RFC 3339] date and time, like `2024-05-01T18:30:00+02:00`.
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    Rfc3339,
+    /// Date and time, like `2024-05-01 16:30`.
+    DateTime,
+    /// Date without time, like `2024-05-01`.
+    Date,
+    /// Discord timestamp markup, like `<t:1714581000>` or `<t:1714581000:R>`.
+    Timestamp,
+}
+
+impl DateTimeFormat {
+    /// All supported formats.
+    pub const ALL: &'static [Self] = &[Self::Rfc3339, Self::DateTime, Self::Date, Self::Timestamp];
+}
+
+/// Formats accepted with the given option settings.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn accepted_formats(settings: &super::internal::OptionSettings) -> &[DateTimeFormat] {
+    settings.formats.as_deref().unwrap_or(DateTimeFormat::ALL)
+}
+
+/// Parse a Discord timestamp markup like `<t:1714581000:R>` into a Unix
+/// timestamp.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn parse_timestamp_markup(input: &str) -> Option<i64> {
+    let inner = input.strip_prefix("<t:")?.strip_suffix('>')?;
+    let timestamp = match inner.split_once(':') {
+        Some((timestamp, style)) if style.len() == 1 && "tTdDfFsSR".contains(style) => timestamp,
+        Some(_) => return None,
+        None => inner,
+    };
+
+    timestamp.parse().ok()
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+    use twilight_model::application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+    };
+
+    use super::{accepted_formats, parse_timestamp_markup, DateTimeFormat};
+    use crate::{
+        command::{
+            internal::{CommandOptionData, CreateOptionData, OptionSettings, SupportedSettings},
+            CommandOption, CreateOption,
+        },
+        error::ParseOptionErrorType,
+    };
+
+    /// Parse a [`DateTime`] using the given format.
+    ///
+    /// The offset of RFC 3339 values is kept, other formats are in UTC.
+    fn parse_datetime(input: &str, format: DateTimeFormat) -> Option<DateTime<FixedOffset>> {
+        let datetime = match format {
+            DateTimeFormat::Rfc3339 => return DateTime::parse_from_rfc3339(input).ok(),
+            DateTimeFormat::DateTime => NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M")
+                .ok()
+                .map(|datetime| datetime.and_utc()),
+            DateTimeFormat::Date => NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(Default::default()).and_utc()),
+            DateTimeFormat::Timestamp => {
+                parse_timestamp_markup(input).and_then(|secs| DateTime::from_timestamp(secs, 0))
+            }
+        };
+
+        datetime.map(|datetime| datetime.fixed_offset())
+    }
+
+    /// Parse a string option into a [`DateTime`] in one of the accepted
+    /// formats.
+    fn parse_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        settings: &OptionSettings,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<DateTime<FixedOffset>, ParseOptionErrorType> {
+        let value = String::from_option(value, data, resolved)?;
+        let trimmed = value.trim();
+
+        accepted_formats(settings)
+            .iter()
+            .find_map(|format| parse_datetime(trimmed, *format))
+            .ok_or(ParseOptionErrorType::InvalidDateTime(value))
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl CommandOption for DateTime<Utc> {
+        const SETTINGS: SupportedSettings = SupportedSettings::FORMATS;
+
+        fn from_option(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            Self::from_option_with(value, data, &OptionSettings::default(), resolved)
+        }
+
+        fn from_option_with(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            settings: &OptionSettings,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            parse_option(value, data, settings, resolved).map(|datetime| datetime.to_utc())
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl CommandOption for NaiveDate {
+        const SETTINGS: SupportedSettings = SupportedSettings::FORMATS;
+
+        fn from_option(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            Self::from_option_with(value, data, &OptionSettings::default(), resolved)
+        }
+
+        fn from_option_with(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            settings: &OptionSettings,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            parse_option(value, data, settings, resolved).map(|datetime| datetime.date_naive())
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl CreateOption for DateTime<Utc> {
+        fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+            data.into_option(CommandOptionType::String)
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl CreateOption for NaiveDate {
+        fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+            data.into_option(CommandOptionType::String)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use time::{
+        format_description::{well_known::Rfc3339, FormatItem},
+        macros::format_description,
+        Date, OffsetDateTime, PrimitiveDateTime, Time,
+    };
+    use twilight_model::application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+    };
+
+    use super::{accepted_formats, parse_timestamp_markup, DateTimeFormat};
+    use crate::{
+        command::{
+            internal::{CommandOptionData, CreateOptionData, OptionSettings, SupportedSettings},
+            CommandOption, CreateOption,
+        },
+        error::ParseOptionErrorType,
+    };
+
+    const DATE_TIME: &[FormatItem<'_>] =
+        format_description!("[year]-[month]-[day] [hour]:[minute]");
+    const DATE: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+    /// Parse an [`OffsetDateTime`] using the given format.
+    fn parse_datetime(input: &str, format: DateTimeFormat) -> Option<OffsetDateTime> {
+        match format {
+            DateTimeFormat::Rfc3339 => OffsetDateTime::parse(input, &Rfc3339).ok(),
+            DateTimeFormat::DateTime => PrimitiveDateTime::parse(input, DATE_TIME)
+                .ok()
+                .map(PrimitiveDateTime::assume_utc),
+            DateTimeFormat::Date => Date::parse(input, DATE)
+                .ok()
+                .map(|date| date.with_time(Time::MIDNIGHT).assume_utc()),
+            DateTimeFormat::Timestamp => parse_timestamp_markup(input)
+                .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok()),
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    impl CommandOption for OffsetDateTime {
+        const SETTINGS: SupportedSettings = SupportedSettings::FORMATS;
+
+        fn from_option(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            Self::from_option_with(value, data, &OptionSettings::default(), resolved)
+        }
+
+        fn from_option_with(
+            value: CommandOptionValue,
+            data: CommandOptionData,
+            settings: &OptionSettings,
+            resolved: Option<&InteractionDataResolved>,
+        ) -> Result<Self, ParseOptionErrorType> {
+            let value = String::from_option(value, data, resolved)?;
+            let trimmed = value.trim();
+
+            accepted_formats(settings)
+                .iter()
+                .find_map(|format| parse_datetime(trimmed, *format))
+                .ok_or(ParseOptionErrorType::InvalidDateTime(value))
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    impl CreateOption for OffsetDateTime {
+        fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+            data.into_option(CommandOptionType::String)
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+    time::Duration,
+};
+
+use twilight_model::application::{
+    command::{CommandOption as TwilightCommandOption, CommandOptionType, CommandOptionValue},
+    interaction::{
+        application_command::CommandOptionValue as InteractionValue, InteractionDataResolved,
+    },
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// A duration parsed from a human-readable string.
+///
+/// This type registers a `STRING` option and parses durations such as `90s`,
+/// `1h30m` or `2d 12h`. Each component is an integer followed by a unit:
+///
+/// | Unit    | Accepted suffixes                        |
+/// |---------|------------------------------------------|
+/// | Seconds | `s`, `sec`, `secs`, `second`, `seconds`  |
+/// | Minutes | `m`, `min`, `mins`, `minute`, `minutes`  |
+/// | Hours   | `h`, `hr`, `hrs`, `hour`, `hours`        |
+/// | Days    | `d`, `day`, `days`                       |
+/// | Weeks   | `w`, `week`, `weeks`                     |
+///
+/// The `min_value` and `max_value` attributes can be used to restrict the
+/// duration, in seconds. They are checked when parsing the option and are not
+/// sent to Discord.
+///
+/// The [`Display`] implementation returns the normalized form of the duration
+/// (like `1h30m`), which can be used to suggest values in autocomplete
+/// interactions with [`AutocompleteValue<HumanDuration>`].
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, HumanDuration};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "remind", desc = "Set a reminder")]
+/// struct RemindCommand {
+///     /// Delay before the reminder
+///     #[command(min_value = 60, max_value = 604800)]
+///     delay: HumanDuration,
+/// }
+///
+/// let duration: HumanDuration = "1h 90m".parse().unwrap();
+/// assert_eq!(duration.to_string(), "2h30m");
+/// ```
+///
+/// [`AutocompleteValue<HumanDuration>`]: super::AutocompleteValue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration(pub Duration);
+
+impl HumanDuration {
+    /// Parse a duration in seconds, returning [`None`] if invalid.
+    fn parse_secs(input: &str) -> Option<u64> {
+        let mut chars = input.trim().chars().peekable();
+        let mut total: u64 = 0;
+        let mut components = 0;
+
+        while chars.peek().is_some() {
+            let mut value: u64 = 0;
+            let mut digits = 0;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                value = value.checked_mul(10)?.checked_add(u64::from(digit))?;
+                digits += 1;
+                chars.next();
+            }
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            let mut unit = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+                unit.extend(c.to_lowercase());
+            }
+
+            if digits == 0 || unit.is_empty() {
+                return None;
+            }
+
+            let multiplier = match &*unit {
+                "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+                "d" | "day" | "days" => 86400,
+                "w" | "week" | "weeks" => 604800,
+                _ => return None,
+            };
+
+            total = total.checked_add(value.checked_mul(multiplier)?)?;
+            components += 1;
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        }
+
+        if components == 0 {
+            return None;
+        }
+
+        Some(total)
+    }
+}
+
+impl FromStr for HumanDuration {
+    type Err = ParseOptionErrorType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::parse_secs(s) {
+            Some(secs) => Ok(Self(Duration::from_secs(secs))),
+            None => Err(ParseOptionErrorType::InvalidDuration(s.to_owned())),
+        }
+    }
+}
+
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        const UNITS: [(u64, &str); 5] = [
+            (604800, "w"),
+            (86400, "d"),
+            (3600, "h"),
+            (60, "m"),
+            (1, "s"),
+        ];
+
+        let mut secs = self.0.as_secs();
+        if secs == 0 {
+            return write!(f, "0s");
+        }
+
+        for (unit_secs, suffix) in UNITS {
+            if secs >= unit_secs {
+                write!(f, "{}{suffix}", secs / unit_secs)?;
+                secs %= unit_secs;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(value: Duration) -> Self {
+        Self(value)
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(value: HumanDuration) -> Self {
+        value.0
+    }
+}
+
+/// Convert a `min_value` or `max_value` in seconds into a [`Duration`].
+fn bound_duration(value: Option<CommandOptionValue>) -> Option<Duration> {
+    match value? {
+        CommandOptionValue::Integer(secs) => Some(Duration::from_secs(secs.max(0) as u64)),
+        CommandOptionValue::Number(secs) => Duration::try_from_secs_f64(secs.max(0.0)).ok(),
+    }
+}
+
+impl CommandOption for HumanDuration {
+    fn from_option(
+        value: InteractionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let min = bound_duration(data.min_value);
+        let max = bound_duration(data.max_value);
+        let duration: Self = String::from_option(value, data, resolved)?.parse()?;
+
+        if min.is_some_and(|min| duration.0 < min) || max.is_some_and(|max| duration.0 > max) {
+            return Err(ParseOptionErrorType::DurationOutOfRange(duration.0));
+        }
+
+        Ok(duration)
+    }
+}
+
+impl CreateOption for HumanDuration {
+    fn create_option(mut data: CreateOptionData) -> TwilightCommandOption {
+        // Bounds are checked when parsing and are not valid on `STRING` options.
+        data.data.min_value = None;
+        data.data.max_value = None;
+
+        data.into_option(CommandOptionType::String)
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+    },
+    channel::message::EmojiReactionType,
+    id::{marker::EmojiMarker, Id},
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// An emoji parsed from a string option.
+///
+/// This type registers a `STRING` option and accepts either a custom emoji
+/// (`<:name:id>` or `<a:name:id>` for animated emojis, which is what the
+/// Discord client sends when selecting a custom emoji) or a single Unicode
+/// emoji like `👍` or `🏳️‍🌈`. Other values are rejected with an
+/// [`InvalidEmoji`] error.
+///
+/// The [`Display`] implementation returns the emoji markup, and the type can be
+/// converted into an [`EmojiReactionType`] to react to a message.
+///
+/// ```
+/// use twilight_interactions::command::EmojiInput;
+///
+/// let emoji: EmojiInput = "<a:party:1234>".parse().unwrap();
+/// assert_eq!(emoji.name(), "party");
+/// assert!(emoji.id().is_some());
+///
+/// let emoji: EmojiInput = "👍".parse().unwrap();
+/// assert_eq!(emoji, EmojiInput::Unicode("👍".into()));
+/// ```
+///
+/// [`InvalidEmoji`]: ParseOptionErrorType::InvalidEmoji
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EmojiInput {
+    /// Custom emoji.
+    Custom {
+        /// Emoji identifier.
+        id: Id<EmojiMarker>,
+        /// Emoji name.
+        name: String,
+        /// Whether the emoji is animated.
+        animated: bool,
+    },
+    /// Unicode emoji.
+    Unicode(String),
+}
+
+impl EmojiInput {
+    /// Identifier of the emoji, if it is a custom emoji.
+    pub const fn id(&self) -> Option<Id<EmojiMarker>> {
+        match self {
+            Self::Custom { id, .. } => Some(*id),
+            Self::Unicode(_) => None,
+        }
+    }
+
+    /// Name of the custom emoji, or the emoji itself if it is a Unicode emoji.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Custom { name, .. } => name,
+            Self::Unicode(emoji) => emoji,
+        }
+    }
+
+    /// Parse a custom emoji markup like `<a:name:id>`.
+    fn parse_custom(input: &str) -> Option<Self> {
+        let inner = input.strip_prefix('<')?.strip_suffix('>')?;
+        let (animated, inner) = match inner.strip_prefix("a:") {
+            Some(inner) => (true, inner),
+            None => (false, inner.strip_prefix(':')?),
+        };
+
+        let (name, id) = inner.split_once(':')?;
+        let valid_name = (2..=32).contains(&name.chars().count())
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+        if !valid_name {
+            return None;
+        }
+
+        Some(Self::Custom {
+            id: Id::new_checked(id.parse().ok()?)?,
+            name: name.to_owned(),
+            animated,
+        })
+    }
+}
+
+impl FromStr for EmojiInput {
+    type Err = ParseOptionErrorType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+
+        if let Some(emoji) = Self::parse_custom(input) {
+            return Ok(emoji);
+        }
+
+        if is_unicode_emoji(input) {
+            return Ok(Self::Unicode(input.to_owned()));
+        }
+
+        Err(ParseOptionErrorType::InvalidEmoji(s.to_owned()))
+    }
+}
+
+impl Display for EmojiInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Custom { id, name, animated } => {
+                let prefix = if *animated { "a" } else { "" };
+                write!(f, "<{prefix}:{name}:{id}>")
+            }
+            Self::Unicode(emoji) => f.write_str(emoji),
+        }
+    }
+}
+
+impl From<EmojiInput> for EmojiReactionType {
+    fn from(value: EmojiInput) -> Self {
+        match value {
+            EmojiInput::Custom { id, name, animated } => Self::Custom {
+                animated,
+                id,
+                name: Some(name),
+            },
+            EmojiInput::Unicode(name) => Self::Unicode { name },
+        }
+    }
+}
+
+impl CommandOption for EmojiInput {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        String::from_option(value, data, resolved)?.parse()
+    }
+}
+
+impl CreateOption for EmojiInput {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+const BLACK_FLAG: char = '\u{1F3F4}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// Check whether the input is a single Unicode emoji.
+///
+/// This supports emojis with variation selectors and skin tone modifiers,
+/// keycaps, flags and zero width joiner sequences. It does not check whether
+/// the sequence is an emoji recommended by Unicode.
+fn is_unicode_emoji(input: &str) -> bool {
+    let chars: Vec<char> = input.chars().collect();
+
+    match chars.as_slice() {
+        [] => false,
+        // Keycap sequences like `1️⃣`
+        [base, VARIATION_SELECTOR, KEYCAP] | [base, KEYCAP] => {
+            matches!(base, '0'..='9' | '#' | '*')
+        }
+        // Flags made of two regional indicators like `🇫🇷`
+        [first, second] if is_regional_indicator(*first) => is_regional_indicator(*second),
+        // Subdivision flags like `🏴󠁧󠁢󠁳󠁣󠁴󠁿`
+        [BLACK_FLAG, tags @ .., CANCEL_TAG] if !tags.is_empty() => tags.iter().all(|c| is_tag(*c)),
+        // Zero width joiner sequences like `👩‍💻`, each element being an emoji
+        // optionally followed by a variation selector or skin tone modifier.
+        _ => chars
+            .split(|c| *c == ZERO_WIDTH_JOINER)
+            .all(|element| match element {
+                [base, modifiers @ ..] => {
+                    is_emoji_base(*base)
+                        && modifiers
+                            .iter()
+                            .all(|c| *c == VARIATION_SELECTOR || is_skin_tone(*c))
+                }
+                [] => false,
+            }),
+    }
+}
+
+/// Check whether a character can start an emoji.
+fn is_emoji_base(c: char) -> bool {
+    matches!(
+        c,
+        '\u{A9}'
+            | '\u{AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{21AA}'
+            | '\u{231A}'..='\u{23FF}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{25FE}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    ) && !is_regional_indicator(c)
+        && !is_skin_tone(c)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+fn is_tag(c: char) -> bool {
+    ('\u{E0020}'..='\u{E007E}').contains(&c)
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+/// Maximum number of choices returned to an autocomplete interaction.
+const MAX_CHOICES: usize = 25;
+
+/// Fuzzy matcher producing choices for autocomplete interactions.
+///
+/// This type ranks a list of candidate choices against the user input of an
+/// autocomplete interaction (the value of [`AutocompleteValue::Focused`]), and
+/// returns the best matches as [`CommandOptionChoice`], ready to be sent in the
+/// interaction response.
+///
+/// Candidates are ranked as follows:
+///
+/// 1. Names starting with the input.
+/// 2. Names containing the input, the earliest match first.
+/// 3. Names within a small edit distance of the input (typos), the closest
+///    first.
+///
+/// Candidates that do not match are discarded, and ties are broken by the
+/// order in which candidates were added. Matching ignores case and common
+/// diacritics, so `eleve` matches `Élève`. At most 25 choices are returned,
+/// which is the maximum allowed by Discord.
+///
+/// If a [locale](Self::locale) is set, candidates are matched against their
+/// name localized in this locale, falling back to their default name.
+/// Otherwise, all names of the candidates are matched.
+///
+/// ```
+/// use twilight_interactions::command::FuzzyMatcher;
+///
+/// let matcher = FuzzyMatcher::from_strings(["Paris", "Lyon", "Marseille", "Montpellier"]);
+/// let names: Vec<_> = matcher
+///     .matches("m")
+///     .into_iter()
+///     .map(|choice| choice.name)
+///     .collect();
+///
+/// assert_eq!(names, ["Marseille", "Montpellier"]);
+/// assert_eq!(matcher.matches("lyno")[0].name, "Lyon");
+/// ```
+///
+/// [`AutocompleteValue::Focused`]: super::AutocompleteValue::Focused
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FuzzyMatcher {
+    candidates: Vec<CommandOptionChoice>,
+    locale: Option<String>,
+}
+
+impl FuzzyMatcher {
+    /// Create a new [`FuzzyMatcher`] with the given candidate choices.
+    pub fn new(choices: impl IntoIterator<Item = CommandOptionChoice>) -> Self {
+        Self {
+            candidates: choices.into_iter().collect(),
+            locale: None,
+        }
+    }
+
+    /// Create a new [`FuzzyMatcher`] from a list of strings.
+    ///
+    /// Each string is used as the name and value of a `STRING` choice.
+    pub fn from_strings<T: Into<String>>(items: impl IntoIterator<Item = T>) -> Self {
+        Self::new(items.into_iter().map(|item| {
+            let name = item.into();
+
+            CommandOptionChoice {
+                value: CommandOptionChoiceValue::String(name.clone()),
+                name,
+                name_localizations: None,
+            }
+        }))
+    }
+
+    /// Add a candidate choice.
+    pub fn push(&mut self, choice: CommandOptionChoice) {
+        self.candidates.push(choice);
+    }
+
+    /// Set the locale used to match the localized names of the candidates.
+    ///
+    /// This is usually the locale of the user that triggered the interaction.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+
+        self
+    }
+
+    /// Get the candidate choices matching the input, best match first.
+    pub fn matches(&self, input: &str) -> Vec<CommandOptionChoice> {
+        let input = fold(input.trim());
+
+        let mut ranked: Vec<_> = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, choice)| {
+                self.names(choice)
+                    .into_iter()
+                    .filter_map(|name| Rank::new(&input, &fold(name)))
+                    .min()
+                    .map(|rank| (rank, index, choice))
+            })
+            .collect();
+
+        // The sort is stable and the index is part of the key, so candidates
+        // with the same rank keep their original order.
+        ranked.sort_by_key(|(rank, index, _)| (*rank, *index));
+
+        ranked
+            .into_iter()
+            .take(MAX_CHOICES)
+            .map(|(_, _, choice)| choice.clone())
+            .collect()
+    }
+
+    /// Names of a candidate matched against the input
+    fn names<'a>(&self, choice: &'a CommandOptionChoice) -> Vec<&'a str> {
+        let localizations = choice.name_localizations.as_ref();
+        let mut names = Vec::new();
+
+        match &self.locale {
+            Some(locale) => {
+                names.extend(localizations.and_then(|names| names.get(locale)));
+                names.push(&choice.name);
+            }
+            None => {
+                names.push(&choice.name);
+                names.extend(localizations.into_iter().flat_map(HashMap::values));
+            }
+        }
+
+        names.into_iter().map(String::as_str).collect()
+    }
+}
+
+impl FromIterator<CommandOptionChoice> for FuzzyMatcher {
+    fn from_iter<T: IntoIterator<Item = CommandOptionChoice>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl Extend<CommandOptionChoice> for FuzzyMatcher {
+    fn extend<T: IntoIterator<Item = CommandOptionChoice>>(&mut self, iter: T) {
+        self.candidates.extend(iter);
+    }
+}
+
+/// Rank of a candidate name, lower is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Rank {
+    /// The name starts with the input.
+    Prefix,
+    /// The name contains the input at the given position.
+    Substring(usize),
+    /// The name is at the given edit distance of the input.
+    Distance(usize),
+}
+
+impl Rank {
+    /// Rank a folded name against a folded input, returning [`None`] if the
+    /// name does not match.
+    fn new(input: &[char], name: &[char]) -> Option<Self> {
+        if name.starts_with(input) {
+            return Some(Self::Prefix);
+        }
+
+        if let Some(position) = name.windows(input.len()).position(|window| window == input) {
+            return Some(Self::Substring(position));
+        }
+
+        // The input is compared to the beginning of the name since the user
+        // may not have finished typing.
+        let end = name.len().min(input.len());
+        let distance = edit_distance(input, &name[..end]);
+
+        (distance <= input.len() / 3).then_some(Self::Distance(distance))
+    }
+}
+
+/// Edit distance between two strings.
+///
+/// This is the optimal string alignment distance, which counts insertions,
+/// deletions, substitutions and transpositions of adjacent characters.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(matrix[i - 2][j - 2] + 1);
+            }
+
+            matrix[i][j] = distance;
+        }
+    }
+
+    matrix[a.len()][b.len()]
+}
+
+/// Fold a string for case- and diacritic-insensitive matching.
+fn fold(value: &str) -> Vec<char> {
+    let mut folded = Vec::with_capacity(value.len());
+
+    for c in value.chars().flat_map(char::to_lowercase) {
+        match fold_char(c) {
+            Some(base) => folded.extend(base.chars()),
+            None => folded.push(c),
+        }
+    }
+
+    folded
+}
+
+/// Base form of common Latin letters with diacritics.
+fn fold_char(c: char) -> Option<&'static str> {
+    let base = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' | 'ĺ' | 'ļ' => "l",
+        'ñ' | 'ń' | 'ň' | 'ņ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'ŕ' | 'ř' | 'ŗ' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ť' | 'ţ' | 'ț' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        _ => return None,
+    };
+
+    Some(base)
+}
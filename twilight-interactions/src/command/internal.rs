@@ -12,9 +12,10 @@ use twilight_model::{
         CommandOption, CommandOptionChoice, CommandOptionType, CommandOptionValue,
     },
     channel::ChannelType,
+    id::{marker::GuildMarker, Id},
 };
 
-use super::{DescLocalizations, NameLocalizations};
+use super::{DateTimeFormat, DescLocalizations, NameLocalizations};
 
 /// Internal representation of localization types ([`NameLocalizations`] and
 /// [`DescLocalizations`]).
@@ -24,16 +25,34 @@ pub struct LocalizationsInternal {
     pub localizations: Option<HashMap<String, String>>,
 }
 
+impl LocalizationsInternal {
+    /// Append an index to the fallback and localized values.
+    ///
+    /// This is used to name repeated options like `file1`, `file2`, etc.
+    pub fn numbered(mut self, index: u16) -> Self {
+        let suffix = index.to_string();
+
+        self.fallback.push_str(&suffix);
+        for value in self.localizations.iter_mut().flat_map(HashMap::values_mut) {
+            value.push_str(&suffix);
+        }
+
+        self
+    }
+}
+
 /// Convert a type into a [`LocalizationsInternal`].
 pub trait IntoLocalizationsInternal {
     fn into_localizations(self) -> LocalizationsInternal;
 }
 
+/// Descriptions without localizations are registered without localization
+/// dictionary, like descriptions set with the `desc` attribute.
 impl IntoLocalizationsInternal for DescLocalizations {
     fn into_localizations(self) -> LocalizationsInternal {
         LocalizationsInternal {
             fallback: self.fallback,
-            localizations: Some(self.localizations),
+            localizations: (!self.localizations.is_empty()).then_some(self.localizations),
         }
     }
 }
@@ -104,6 +123,124 @@ pub struct CommandOptionData {
     pub min_length: Option<u16>,
 }
 
+/// Settings of a command option only used when parsing.
+///
+/// Unlike [`CommandOptionData`], these settings are not registered with the
+/// command and only apply to some option types. They are set with field
+/// attributes and received in [`CommandOption::from_option_with`].
+///
+/// [`CommandOption::from_option_with`]: super::CommandOption::from_option_with
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct OptionSettings {
+    /// Accepted date and time formats. Only for date and time option types.
+    pub formats: Option<Vec<DateTimeFormat>>,
+    /// Separator between list items. Only for [`Vec<T>`] option types.
+    pub separator: Option<String>,
+    /// Maximum number of list items. Only for [`Vec<T>`] option types.
+    pub max_items: Option<u16>,
+    /// Minimum number of list items. Only for [`Vec<T>`] option types.
+    pub min_items: Option<u16>,
+    /// Guild the value must belong to, `Some(None)` for direct messages. Only
+    /// for [`MessageLink`] option type.
+    ///
+    /// [`MessageLink`]: super::MessageLink
+    pub same_guild: Option<Option<Id<GuildMarker>>>,
+}
+
+impl OptionSettings {
+    /// Create a new [`OptionSettings`] with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the accepted date and time formats.
+    pub fn formats(mut self, formats: Vec<DateTimeFormat>) -> Self {
+        self.formats = Some(formats);
+
+        self
+    }
+
+    /// Set the separator between list items.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = Some(separator.into());
+
+        self
+    }
+
+    /// Set the maximum number of list items.
+    pub fn max_items(mut self, max_items: u16) -> Self {
+        self.max_items = Some(max_items);
+
+        self
+    }
+
+    /// Set the minimum number of list items.
+    pub fn min_items(mut self, min_items: u16) -> Self {
+        self.min_items = Some(min_items);
+
+        self
+    }
+
+    /// Only accept values from the guild where the command was invoked.
+    ///
+    /// The guild is taken from [`CommandContext::guild_id`], and values are
+    /// not checked if it is unknown.
+    ///
+    /// [`CommandContext::guild_id`]: super::CommandContext::guild_id
+    pub fn same_guild(mut self, guild_id: Option<Option<Id<GuildMarker>>>) -> Self {
+        self.same_guild = guild_id;
+
+        self
+    }
+}
+
+/// Parsing settings read by an option type.
+///
+/// This is the value of [`CommandOption::SETTINGS`], used by the derive macro
+/// to reject field attributes that would be ignored by the option type.
+///
+/// [`CommandOption::SETTINGS`]: super::CommandOption::SETTINGS
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SupportedSettings(u8);
+
+impl SupportedSettings {
+    /// No settings are read.
+    pub const NONE: Self = Self(0);
+    /// The `formats` setting.
+    pub const FORMATS: Self = Self(1);
+    /// The `separator` setting.
+    pub const SEPARATOR: Self = Self(1 << 1);
+    /// The `min_items` and `max_items` settings.
+    pub const ITEMS: Self = Self(1 << 2);
+    /// The `same_guild` setting.
+    pub const SAME_GUILD: Self = Self(1 << 3);
+
+    /// Combine the settings with other settings.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Whether all the other settings are included.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Check that an option type reads a setting.
+///
+/// This is used by the code generated by the derive macro in a `const` item,
+/// so that unsupported field attributes are rejected at compile time.
+pub const fn check_setting(
+    supported: SupportedSettings,
+    setting: SupportedSettings,
+    message: &str,
+) {
+    if !supported.contains(setting) {
+        panic!("{}", message);
+    }
+}
+
 /// Builder to convert a [`CreateOptionData`] into a [`CommandOption`].
 pub struct CreateOptionBuilder {
     kind: CommandOptionType,
@@ -169,3 +306,15 @@ impl CreateOptionData {
         self.builder(kind).build()
     }
 }
+
+/// Check the nesting depth of a subcommand at compile time.
+///
+/// Discord only allows subcommands in subcommand groups, so the depth of a
+/// subcommand must be at most 1. This is used by the code generated by the
+/// derive macro in a `const` item.
+pub const fn check_nesting_depth(depth: usize) {
+    assert!(
+        depth <= 1,
+        "subcommand groups cannot contain other subcommand groups"
+    );
+}
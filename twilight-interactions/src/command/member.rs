@@ -0,0 +1,168 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{
+            application_command::CommandOptionValue, InteractionDataResolved, InteractionMember,
+        },
+    },
+    guild::{Permissions, Role},
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
+    user::User,
+    util::Timestamp,
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption, ResolvedUser,
+};
+use crate::error::ParseOptionErrorType;
+
+/// A resolved guild member.
+///
+/// This struct implements [`CommandOption`] and registers a `USER` option.
+/// Unlike [`ResolvedUser`], parsing fails with a [`MemberNotFound`] error if
+/// the user is not a member of the guild (or if the command was invoked
+/// outside of a guild).
+///
+/// Role hierarchy helpers require the list of the guild roles, which is not
+/// included in interactions.
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, ResolvedMember};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "kick", desc = "Kick a member")]
+/// struct KickCommand {
+///     /// Member to kick
+///     member: ResolvedMember,
+/// }
+/// ```
+///
+/// [`MemberNotFound`]: ParseOptionErrorType::MemberNotFound
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedMember {
+    /// The resolved user.
+    pub user: User,
+    /// The resolved member.
+    pub member: InteractionMember,
+}
+
+impl ResolvedMember {
+    /// ID of the member.
+    pub const fn id(&self) -> Id<UserMarker> {
+        self.user.id
+    }
+
+    /// IDs of the member roles.
+    pub fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.member.roles
+    }
+
+    /// Nickname of the member in the guild.
+    pub fn nick(&self) -> Option<&str> {
+        self.member.nick.as_deref()
+    }
+
+    /// Name displayed in the guild.
+    ///
+    /// This is the member nickname, or the user global name or username if
+    /// the member has no nickname.
+    pub fn display_name(&self) -> &str {
+        self.member
+            .nick
+            .as_deref()
+            .or(self.user.global_name.as_deref())
+            .unwrap_or(&self.user.name)
+    }
+
+    /// Timestamp until which the member is timed out.
+    ///
+    /// The timestamp may be in the past if the timeout has expired.
+    pub const fn communication_disabled_until(&self) -> Option<Timestamp> {
+        self.member.communication_disabled_until
+    }
+
+    /// Whether the member is currently timed out.
+    pub fn is_timed_out(&self) -> bool {
+        let Some(until) = self.member.communication_disabled_until else {
+            return false;
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.as_micros());
+
+        i128::from(until.as_micros()) > now as i128
+    }
+
+    /// Permissions of the member in the channel where the command was
+    /// invoked, including overwrites.
+    pub const fn permissions(&self) -> Permissions {
+        self.member.permissions
+    }
+
+    /// Highest role of the member.
+    ///
+    /// Returns [`None`] if the member has no role other than `@everyone`.
+    pub fn highest_role<'a>(&self, guild_roles: &'a [Role]) -> Option<&'a Role> {
+        guild_roles
+            .iter()
+            .filter(|role| self.member.roles.contains(&role.id))
+            .max()
+    }
+
+    /// Whether the highest role of the member is above the highest role of
+    /// another member.
+    ///
+    /// This can be used to check whether a member can moderate another one.
+    /// Guild owners are not taken into account.
+    pub fn outranks(&self, other: &ResolvedMember, guild_roles: &[Role]) -> bool {
+        self.highest_role(guild_roles) > other.highest_role(guild_roles)
+    }
+
+    /// Whether the highest role of the member is above a role.
+    ///
+    /// This can be used to check whether a member can assign a role.
+    pub fn outranks_role(&self, role: &Role, guild_roles: &[Role]) -> bool {
+        self.highest_role(guild_roles)
+            .is_some_and(|highest| highest > role)
+    }
+}
+
+impl From<ResolvedMember> for ResolvedUser {
+    fn from(value: ResolvedMember) -> Self {
+        Self {
+            resolved: value.user,
+            member: Some(value.member),
+        }
+    }
+}
+
+impl CommandOption for ResolvedMember {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let user = ResolvedUser::from_option(value, data, resolved)?;
+
+        match user.member {
+            Some(member) => Ok(Self {
+                user: user.resolved,
+                member,
+            }),
+            None => Err(ParseOptionErrorType::MemberNotFound(user.resolved.id)),
+        }
+    }
+}
+
+impl CreateOption for ResolvedMember {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::User)
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{
+            application_command::CommandOptionValue, InteractionChannel, InteractionDataResolved,
+        },
+    },
+    guild::Role,
+    id::{
+        marker::{ChannelMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption, ResolvedUser,
+};
+use crate::error::ParseOptionErrorType;
+
+/// A user parsed from an ID or a mention.
+///
+/// This type registers a `STRING` option and accepts a raw user ID or a user
+/// mention (`<@id>` or `<@!id>`). Unlike `USER` options, it can be used to
+/// target users that are not visible to the client, such as in unban
+/// commands. Other values are rejected with an [`InvalidMention`] error.
+///
+/// The user is resolved if it is present in the interaction resolved data.
+///
+/// ```
+/// use twilight_interactions::command::{AnyUser, CommandModel, CreateCommand};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "unban", desc = "Unban a user")]
+/// struct UnbanCommand {
+///     /// ID or mention of the user to unban
+///     user: AnyUser,
+/// }
+/// ```
+///
+/// [`InvalidMention`]: ParseOptionErrorType::InvalidMention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyUser {
+    /// ID of the user.
+    pub id: Id<UserMarker>,
+    /// The resolved user, if found.
+    pub resolved: Option<ResolvedUser>,
+}
+
+/// A role parsed from an ID or a mention.
+///
+/// This type registers a `STRING` option and accepts a raw role ID or a role
+/// mention (`<@&id>`). Other values are rejected with an [`InvalidMention`]
+/// error.
+///
+/// The role is resolved if it is present in the interaction resolved data.
+///
+/// [`InvalidMention`]: ParseOptionErrorType::InvalidMention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyRole {
+    /// ID of the role.
+    pub id: Id<RoleMarker>,
+    /// The resolved role, if found.
+    pub resolved: Option<Role>,
+}
+
+/// A channel parsed from an ID or a mention.
+///
+/// This type registers a `STRING` option and accepts a raw channel ID or a
+/// channel mention (`<#id>`). Other values are rejected with an
+/// [`InvalidMention`] error.
+///
+/// The channel is resolved if it is present in the interaction resolved data.
+/// The `channel_types` attribute is only checked for resolved channels.
+///
+/// [`InvalidMention`]: ParseOptionErrorType::InvalidMention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyChannel {
+    /// ID of the channel.
+    pub id: Id<ChannelMarker>,
+    /// The resolved channel, if found.
+    pub resolved: Option<InteractionChannel>,
+}
+
+/// Parse a raw ID or a mention with one of the given prefixes.
+fn parse_mention<T>(input: &str, prefixes: &[&str]) -> Option<Id<T>> {
+    let input = input.trim();
+    let id = match input.strip_prefix('<').and_then(|i| i.strip_suffix('>')) {
+        Some(mention) => prefixes
+            .iter()
+            .find_map(|prefix| mention.strip_prefix(prefix))?,
+        None => input,
+    };
+
+    if !id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Id::new_checked(id.parse().ok()?)
+}
+
+/// Parse the ID of a mention option.
+fn mention_id<T>(
+    value: CommandOptionValue,
+    data: CommandOptionData,
+    resolved: Option<&InteractionDataResolved>,
+    prefixes: &[&str],
+) -> Result<Id<T>, ParseOptionErrorType> {
+    let value = String::from_option(value, data, resolved)?;
+
+    parse_mention(&value, prefixes).ok_or(ParseOptionErrorType::InvalidMention(value))
+}
+
+impl CommandOption for AnyUser {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let id = mention_id(value, data, resolved, &["@!", "@"])?;
+        let user = resolved.and_then(|resolved| resolved.users.get(&id));
+
+        Ok(Self {
+            id,
+            resolved: user.map(|user| ResolvedUser {
+                resolved: user.clone(),
+                member: resolved.and_then(|resolved| resolved.members.get(&id).cloned()),
+            }),
+        })
+    }
+}
+
+impl CommandOption for AnyRole {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let id = mention_id(value, data, resolved, &["@&"])?;
+
+        Ok(Self {
+            id,
+            resolved: resolved.and_then(|resolved| resolved.roles.get(&id).cloned()),
+        })
+    }
+}
+
+impl CommandOption for AnyChannel {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let channel_types = data.channel_types.clone();
+        let id = mention_id(value, data, resolved, &["#"])?;
+        let channel = resolved.and_then(|resolved| resolved.channels.get(&id).cloned());
+
+        if let (Some(channel), Some(channel_types)) = (&channel, channel_types) {
+            if !channel_types.contains(&channel.kind) {
+                return Err(ParseOptionErrorType::InvalidChannelType(channel.kind));
+            }
+        }
+
+        Ok(Self {
+            id,
+            resolved: channel,
+        })
+    }
+}
+
+impl CreateOption for AnyUser {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+impl CreateOption for AnyRole {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+impl CreateOption for AnyChannel {
+    fn create_option(mut data: CreateOptionData) -> TwilightCommandOption {
+        // Channel types are checked when parsing and are not valid on `STRING`
+        // options.
+        data.data.channel_types = None;
+
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+impl Display for AnyUser {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "<@{}>", self.id)
+    }
+}
+
+impl Display for AnyRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "<@&{}>", self.id)
+    }
+}
+
+impl Display for AnyChannel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "<#{}>", self.id)
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as TwilightCommandOption, CommandOptionType},
+        interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        Id,
+    },
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData, OptionSettings, SupportedSettings},
+    CommandOption, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// A link to a Discord message.
+///
+/// This type registers a `STRING` option and parses message links like
+/// `https://discord.com/channels/<guild>/<channel>/<message>`. Links from the
+/// `ptb.` and `canary.` clients and links to direct messages (using `@me`
+/// instead of the guild ID) are supported. Invalid links are rejected with an
+/// [`InvalidMessageLink`] error.
+///
+/// The `same_guild` attribute can be used to only accept links to messages of
+/// the guild where the command was invoked (or direct messages if the command
+/// was invoked outside of a guild). Other links are rejected with a
+/// [`GuildMismatch`] error. The guild is taken from the [`CommandContext`]
+/// given to [`CommandModel::from_interaction_with`], so commands using this
+/// attribute should be parsed with this method. Links are not checked when
+/// parsing with [`CommandModel::from_interaction`], as the guild is unknown.
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, MessageLink};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "quote", desc = "Quote a message")]
+/// struct QuoteCommand {
+///     /// Link to the message to quote
+///     #[command(same_guild = true)]
+///     message: MessageLink,
+/// }
+///
+/// let link: MessageLink = "https://ptb.discord.com/channels/1/2/3".parse().unwrap();
+/// assert_eq!(link.message_id.get(), 3);
+/// ```
+///
+/// [`InvalidMessageLink`]: ParseOptionErrorType::InvalidMessageLink
+/// [`GuildMismatch`]: ParseOptionErrorType::GuildMismatch
+/// [`CommandContext`]: super::CommandContext
+/// [`CommandModel::from_interaction_with`]: super::CommandModel::from_interaction_with
+/// [`CommandModel::from_interaction`]: super::CommandModel::from_interaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageLink {
+    /// ID of the guild of the message, or [`None`] for direct messages.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the channel of the message.
+    pub channel_id: Id<ChannelMarker>,
+    /// ID of the message.
+    pub message_id: Id<MessageMarker>,
+}
+
+impl MessageLink {
+    /// Parse a message link, returning [`None`] if invalid.
+    fn parse(input: &str) -> Option<Self> {
+        // Links wrapped in `<>` do not display an embed.
+        let input = match input.strip_prefix('<') {
+            Some(inner) => inner.strip_suffix('>')?,
+            None => input,
+        };
+
+        let url = input
+            .strip_prefix("https://")
+            .or_else(|| input.strip_prefix("http://"))?;
+        let url = url
+            .strip_prefix("ptb.")
+            .or_else(|| url.strip_prefix("canary."))
+            .unwrap_or(url);
+        let path = url
+            .strip_prefix("discord.com/channels/")
+            .or_else(|| url.strip_prefix("discordapp.com/channels/"))?;
+
+        let mut segments = path.split('/');
+        let guild_id = match segments.next()? {
+            "@me" => None,
+            guild_id => Some(Id::new_checked(guild_id.parse().ok()?)?),
+        };
+        let channel_id = Id::new_checked(segments.next()?.parse().ok()?)?;
+        let message_id = Id::new_checked(segments.next()?.parse().ok()?)?;
+
+        if segments.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            guild_id,
+            channel_id,
+            message_id,
+        })
+    }
+}
+
+impl FromStr for MessageLink {
+    type Err = ParseOptionErrorType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.trim()).ok_or_else(|| ParseOptionErrorType::InvalidMessageLink(s.to_owned()))
+    }
+}
+
+impl Display for MessageLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("https://discord.com/channels/")?;
+
+        match self.guild_id {
+            Some(guild_id) => write!(f, "{guild_id}")?,
+            None => f.write_str("@me")?,
+        }
+
+        write!(f, "/{}/{}", self.channel_id, self.message_id)
+    }
+}
+
+impl CommandOption for MessageLink {
+    const SETTINGS: SupportedSettings = SupportedSettings::SAME_GUILD;
+
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        String::from_option(value, data, resolved)?.parse()
+    }
+
+    fn from_option_with(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        settings: &OptionSettings,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let link = Self::from_option(value, data, resolved)?;
+
+        match settings.same_guild {
+            Some(guild_id) if link.guild_id != guild_id => {
+                Err(ParseOptionErrorType::GuildMismatch(link.guild_id))
+            }
+            _ => Ok(link),
+        }
+    }
+}
+
+impl CreateOption for MessageLink {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
@@ -80,17 +80,51 @@
 //! The [`CommandOption`] and [`CreateOption`] traits are implemented for the
 //! following types:
 //!
-//! | Command option type | Provided implementations                       |
-//! |---------------------|------------------------------------------------|
-//! | `STRING`            | [`String`], [`Cow`]                            |
-//! | `INTEGER`           | [`i64`]                                        |
-//! | `NUMBER`            | [`f64`]                                        |
-//! | `BOOLEAN`           | [`bool`]                                       |
-//! | `USER`              | [`ResolvedUser`], [`User`], [`Id<UserMarker>`] |
-//! | `CHANNEL`           | [`InteractionChannel`], [`Id<ChannelMarker>`]  |
-//! | `ROLE`              | [`Role`], [`Id<RoleMarker>`]                   |
-//! | `MENTIONABLE`       | [`ResolvedMentionable`], [`Id<GenericMarker>`] |
-//! | `ATTACHMENT`        | [`Attachment`], [`Id<AttachmentMarker>`]       |
+//! | Command option type | Provided implementations                                                 |
+//! |---------------------|--------------------------------------------------------------------------|
+//! | `STRING`            | [`String`], [`Cow`], other string types[^strings]                        |
+//! | `INTEGER`           | [`i64`], other integer types[^integers]                                  |
+//! | `NUMBER`            | [`f64`], [`f32`]                                                         |
+//! | `BOOLEAN`           | [`bool`]                                                                 |
+//! | `USER`              | [`ResolvedUser`], [`ResolvedMember`], [`User`], [`Id<UserMarker>`]       |
+//! | `CHANNEL`           | [`InteractionChannel`], [`Id<ChannelMarker>`], typed channels[^channels] |
+//! | `ROLE`              | [`Role`], [`Id<RoleMarker>`]                                             |
+//! | `MENTIONABLE`       | [`ResolvedMentionable`], [`Id<GenericMarker>`]                           |
+//! | `ATTACHMENT`        | [`Attachment`], [`Id<AttachmentMarker>`]                                 |
+//!
+//! Integer types other than [`i64`] automatically register the `min_value` and
+//! `max_value` allowed by the type, within the range supported by Discord
+//! (-2^53 to 2^53). Bounds set with attributes are kept if they are more
+//! restrictive. Out of range values are rejected with an
+//! [`IntegerOutOfRange`] error instead of being truncated.
+//!
+//! Date and time types are available as `STRING` options with the `chrono`
+//! (`DateTime<Utc>` and `NaiveDate`) and `time` (`OffsetDateTime`) features.
+//! They accept the formats listed in [`DateTimeFormat`], which can be
+//! restricted with the `formats` attribute. Invalid values are rejected with an
+//! [`InvalidDateTime`] error.
+//!
+//! Lists of values can be parsed from a `STRING` option with [`Vec<T>`], where
+//! each item is parsed according to the option type of `T` (like [`String`],
+//! [`i64`] or a choice). Only `STRING`, `INTEGER`, `NUMBER` and `BOOLEAN` item
+//! types are supported. Items are separated by commas by default, which can be
+//! changed with the `separator` attribute. Constraints like `max_length` or
+//! `max_value` apply to each item, and the number of items can be restricted
+//! with `min_items` and `max_items`. Invalid items are reported with an
+//! [`InvalidItem`] error containing the index of the item in the input.
+//!
+//! [^strings]: [`HumanDuration`], [`EmojiInput`], [`MessageLink`], [`AnyUser`],
+//!             [`AnyRole`], [`AnyChannel`] and [`Parsed`], which parses any
+//!             type implementing [`FromStr`].
+//!
+//! [^channels]: [`TextChannel`], [`VoiceChannel`], [`ThreadChannel`],
+//!              [`ForumChannel`] and [`CategoryChannel`], which only accept
+//!              channels of the matching types. The `channel_types` attribute
+//!              can be used to restrict them further.
+//!
+//! [^integers]: [`i32`], [`i16`], [`i8`], [`u64`], [`u32`], [`u16`], [`u8`],
+//!              [`usize`], [`NonZeroU64`], [`NonZeroU32`], [`NonZeroU16`] and
+//!              [`NonZeroU8`].
 //!
 //! Option choices are supported for the `STRING`, `INTEGER` and `NUMBER` option
 //! types. See the [`CommandOption`] and [`CreateOption`] traits documentation
@@ -99,6 +133,14 @@
 //! [`from_interaction`]: CommandModel::from_interaction
 //!
 //! [`Cow`]: std::borrow::Cow
+//! [`FromStr`]: std::str::FromStr
+//! [`NonZeroU64`]: std::num::NonZeroU64
+//! [`NonZeroU32`]: std::num::NonZeroU32
+//! [`NonZeroU16`]: std::num::NonZeroU16
+//! [`NonZeroU8`]: std::num::NonZeroU8
+//! [`IntegerOutOfRange`]: crate::error::ParseOptionErrorType::IntegerOutOfRange
+//! [`InvalidDateTime`]: crate::error::ParseOptionErrorType::InvalidDateTime
+//! [`InvalidItem`]: crate::error::ParseOptionErrorType::InvalidItem
 //! [`User`]: twilight_model::user::User
 //! [`Id<UserMarker>`]: twilight_model::id::Id
 //! [`InteractionChannel`]:
@@ -110,19 +152,37 @@
 //! [`Attachment`]: twilight_model::channel::Attachment
 //! [`Id<AttachmentMarker>`]: twilight_model::id::Id
 
+mod channel;
 mod command_model;
 mod create_command;
+mod datetime;
+mod duration;
+mod emoji;
+mod fuzzy;
+mod member;
+mod mention;
+mod message_link;
+mod parsed;
 
 #[doc(hidden)]
 pub mod internal;
 
+pub use channel::{CategoryChannel, ForumChannel, TextChannel, ThreadChannel, VoiceChannel};
 pub use command_model::{
-    AutocompleteValue, CommandInputData, CommandModel, CommandOption, ResolvedMentionable,
-    ResolvedUser,
+    AutocompleteValue, CommandContext, CommandInputData, CommandModel, CommandOption,
+    OptionConstraint, Partial, ResolvedMentionable, ResolvedUser,
 };
 pub use create_command::{
     ApplicationCommandData, CreateCommand, CreateOption, DescLocalizations, NameLocalizations,
 };
+pub use datetime::DateTimeFormat;
+pub use duration::HumanDuration;
+pub use emoji::EmojiInput;
+pub use fuzzy::FuzzyMatcher;
+pub use member::ResolvedMember;
+pub use mention::{AnyChannel, AnyRole, AnyUser};
+pub use message_link::MessageLink;
+pub use parsed::Parsed;
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use twilight_interactions_derive::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -0,0 +1,98 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use twilight_model::application::{
+    command::{CommandOption as TwilightCommandOption, CommandOptionType},
+    interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+};
+
+use super::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// A value parsed from a string option with [`FromStr`].
+///
+/// This type registers a `STRING` option and parses its value with the
+/// [`FromStr`] implementation of the inner type. It can be used to accept
+/// types like [`IpAddr`] or your own types without implementing the
+/// [`CommandOption`] and [`CreateOption`] traits.
+///
+/// Parsing errors are returned as a [`ParseOptionErrorType::ParseFailed`]
+/// error with the [`Display`] text of the error.
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// use twilight_interactions::command::{CommandModel, CreateCommand, Parsed};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "ping", desc = "Ping an address")]
+/// struct PingCommand {
+///     /// Address to ping
+///     address: Parsed<IpAddr>,
+/// }
+/// ```
+///
+/// [`IpAddr`]: std::net::IpAddr
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parsed<T>(pub T);
+
+impl<T> Parsed<T> {
+    /// Consume the wrapper and return the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Parsed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Parsed<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Parsed<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> CommandOption for Parsed<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = String::from_option(value, data, resolved)?;
+
+        match value.parse() {
+            Ok(parsed) => Ok(Self(parsed)),
+            Err(error) => Err(ParseOptionErrorType::ParseFailed(error.to_string())),
+        }
+    }
+}
+
+impl<T> CreateOption for Parsed<T>
+where
+    T: FromStr,
+{
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
@@ -3,9 +3,17 @@
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
 };
 
-use twilight_model::{application::command::CommandOptionType, channel::ChannelType};
+use twilight_model::{
+    application::command::CommandOptionType,
+    channel::ChannelType,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
 
 /// Error when parsing a command.
 ///
@@ -14,6 +22,7 @@ use twilight_model::{application::command::CommandOptionType, channel::ChannelTy
 ///
 /// [`CommandModel::from_interaction`]: crate::command::CommandModel::from_interaction
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// Received empty option list.
     ///
@@ -21,6 +30,28 @@ pub enum ParseError {
     EmptyOptions,
     /// Error when parsing a command option.
     Option(ParseOptionError),
+    /// An option was provided without another option it requires.
+    ///
+    /// This error is returned by the `requires` attribute.
+    MissingRequirement {
+        /// Name of the provided option.
+        field: String,
+        /// Name of the missing option.
+        requires: String,
+    },
+    /// Two conflicting options were provided.
+    ///
+    /// This error is returned by the `conflicts_with` attribute.
+    Conflict {
+        /// Name of the option declaring the conflict.
+        field: String,
+        /// Name of the conflicting option.
+        conflicts_with: String,
+    },
+    /// None of the options of a group were provided.
+    ///
+    /// This error is returned by the `required_unless` and `one_of` attributes.
+    MissingOneOf(Vec<String>),
 }
 
 impl Error for ParseError {}
@@ -30,6 +61,29 @@ impl Display for ParseError {
         match self {
             ParseError::EmptyOptions => write!(f, "received an empty option list"),
             ParseError::Option(error) => error.fmt(f),
+            ParseError::MissingRequirement { field, requires } => {
+                write!(f, "option `{field}` requires option `{requires}`")
+            }
+            ParseError::Conflict {
+                field,
+                conflicts_with,
+            } => write!(
+                f,
+                "option `{field}` conflicts with option `{conflicts_with}`"
+            ),
+            ParseError::MissingOneOf(fields) => {
+                write!(f, "at least one of the following options is required: ")?;
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "`{field}`")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -49,9 +103,13 @@ impl Error for ParseOptionError {}
 
 impl Display for ParseOptionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "failed to parse option `{}`: ", self.field)?;
+        write!(f, "failed to parse option `{}`: {}", self.field, self.kind)
+    }
+}
 
-        match &self.kind {
+impl Display for ParseOptionErrorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
             ParseOptionErrorType::InvalidType(ty) => write!(f, "invalid type, found {}", ty.kind()),
             ParseOptionErrorType::InvalidChoice(choice) => {
                 write!(f, "invalid choice value, found `{choice}`")
@@ -72,12 +130,50 @@ impl Display for ParseOptionError {
             ParseOptionErrorType::UnknownField => write!(f, "unknown field"),
             ParseOptionErrorType::UnknownSubcommand => write!(f, "unknown subcommand"),
             ParseOptionErrorType::RequiredField => write!(f, "missing required field"),
+            ParseOptionErrorType::Custom(message) => write!(f, "{message}"),
+            ParseOptionErrorType::InvalidDuration(val) => {
+                write!(f, "invalid duration, received `{val}`")
+            }
+            ParseOptionErrorType::DurationOutOfRange(val) => {
+                write!(f, "out of range duration, received `{}s`", val.as_secs())
+            }
+            ParseOptionErrorType::InvalidDateTime(val) => {
+                write!(f, "invalid date or time, received `{val}`")
+            }
+            ParseOptionErrorType::ParseFailed(message) => {
+                write!(f, "failed to parse value: {message}")
+            }
+            ParseOptionErrorType::InvalidItem { index, kind } => {
+                write!(f, "invalid item at index {index}: {kind}")
+            }
+            ParseOptionErrorType::ItemCountOutOfRange(val) => {
+                write!(f, "out of range item count, received `{val}`")
+            }
+            ParseOptionErrorType::InvalidEmoji(val) => {
+                write!(f, "invalid emoji, received `{val}`")
+            }
+            ParseOptionErrorType::InvalidMessageLink(val) => {
+                write!(f, "invalid message link, received `{val}`")
+            }
+            ParseOptionErrorType::GuildMismatch(Some(id)) => {
+                write!(f, "value from another guild, received `{id}`")
+            }
+            ParseOptionErrorType::GuildMismatch(None) => {
+                write!(f, "value from direct messages, expected a guild")
+            }
+            ParseOptionErrorType::InvalidMention(val) => {
+                write!(f, "invalid ID or mention, received `{val}`")
+            }
+            ParseOptionErrorType::MemberNotFound(id) => {
+                write!(f, "user `{id}` is not a member of the guild")
+            }
         }
     }
 }
 
 /// Type of [`ParseOptionError`] that occurred.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ParseOptionErrorType {
     /// Received an invalid option type.
     InvalidType(CommandOptionType),
@@ -99,4 +195,43 @@ pub enum ParseOptionErrorType {
     UnknownField,
     /// Received an unknown subcommand.
     UnknownSubcommand,
+    /// A custom field validator rejected the value.
+    ///
+    /// The inner string is the error message returned by the validator.
+    Custom(String),
+    /// Received a string that is not a valid duration.
+    InvalidDuration(String),
+    /// Received an out of range duration.
+    DurationOutOfRange(Duration),
+    /// Received a string that is not a valid date or time in any of the
+    /// accepted formats.
+    InvalidDateTime(String),
+    /// Failed to parse a string option with [`FromStr`].
+    ///
+    /// The inner string is the [`Display`] text of the parsing error.
+    ///
+    /// [`FromStr`]: std::str::FromStr
+    /// [`Display`]: std::fmt::Display
+    ParseFailed(String),
+    /// Failed to parse an item of a list option.
+    InvalidItem {
+        /// Index of the invalid item.
+        index: usize,
+        /// Error returned when parsing the item.
+        kind: Box<ParseOptionErrorType>,
+    },
+    /// Received a list with an out of range number of items.
+    ItemCountOutOfRange(usize),
+    /// Received a string that is not a custom or Unicode emoji.
+    InvalidEmoji(String),
+    /// Received a string that is not a valid message link.
+    InvalidMessageLink(String),
+    /// Received a value from another guild than the one where the command
+    /// was invoked. The inner value is the received guild ID, or [`None`] for
+    /// direct messages.
+    GuildMismatch(Option<Id<GuildMarker>>),
+    /// Received a string that is not a valid ID or mention.
+    InvalidMention(String),
+    /// Received a user that is not a member of the guild.
+    MemberNotFound(Id<UserMarker>),
 }
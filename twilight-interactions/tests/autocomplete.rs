@@ -1,11 +1,14 @@
+mod common;
+
 use twilight_interactions::command::{
-    AutocompleteValue, CommandInputData, CommandModel, ResolvedUser,
+    AutocompleteValue, CommandModel, CreateCommand, Partial, ResolvedUser,
 };
 use twilight_model::application::{
-    command::CommandOptionType,
-    interaction::application_command::{CommandDataOption, CommandOptionValue},
+    command::CommandOptionType, interaction::application_command::CommandOptionValue,
 };
 
+use common::{input, string_input, subcommand_input};
+
 #[derive(CommandModel, Debug, PartialEq, Eq)]
 #[command(autocomplete = true)]
 struct DemoCommand {
@@ -13,32 +16,223 @@ struct DemoCommand {
     string: AutocompleteValue<String>,
 }
 
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+struct NumericCommand {
+    page: AutocompleteValue<i64>,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+struct TagEditAutocomplete {
+    name: AutocompleteValue<String>,
+    content: Option<String>,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+enum TagAutocomplete {
+    #[command(name = "edit")]
+    Edit(TagEditAutocomplete),
+    #[command(name = "page")]
+    Page(NumericCommand),
+    #[command(name = "delete")]
+    Delete { name: AutocompleteValue<String> },
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+enum ManageAutocomplete {
+    #[command(name = "tag")]
+    Tag(TagAutocomplete),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(
+    name = "edit",
+    desc = "Edit a tag",
+    autocomplete_model = "EditAutocomplete"
+)]
+struct EditCommand {
+    /// Name of the tag
+    #[command(autocomplete = true, max_length = 10)]
+    name: String,
+    /// Position of the tag
+    position: i64,
+    /// New content of the tag
+    #[command(requires = "name")]
+    content: Option<String>,
+}
+
 #[test]
 fn test_autocomplete_model() {
-    let options = vec![
-        CommandDataOption {
-            name: "string".to_string(),
-            value: CommandOptionValue::Focused("test".to_string(), CommandOptionType::String),
-        },
-        CommandDataOption {
-            // Should be ignored
-            name: "number".to_string(),
-            value: CommandOptionValue::Integer(42),
-        },
-    ];
-
-    let data = CommandInputData {
-        options,
-        resolved: None,
-    };
+    let data = input(vec![
+        (
+            "string",
+            CommandOptionValue::Focused("test".to_string(), CommandOptionType::String),
+        ),
+        // Should be ignored
+        ("number", CommandOptionValue::Integer(42)),
+    ]);
 
     let result = DemoCommand::from_interaction(data).unwrap();
 
     assert_eq!(
         DemoCommand {
             user: None,
-            string: AutocompleteValue::Focused("test".to_string())
+            string: AutocompleteValue::Focused(Partial::new(
+                "test".to_string(),
+                CommandOptionType::String
+            ))
         },
         result
     )
 }
+
+#[test]
+fn test_autocomplete_partial() {
+    let parse = |value: &str, kind| {
+        let data = input(vec![(
+            "page",
+            CommandOptionValue::Focused(value.to_string(), kind),
+        )]);
+
+        NumericCommand::from_interaction(data).unwrap().page
+    };
+
+    let partial = Partial::new("12".to_string(), CommandOptionType::Integer);
+    assert_eq!(
+        parse("12", CommandOptionType::Integer),
+        AutocompleteValue::Focused(partial.clone())
+    );
+    assert_eq!(partial.as_i64(), Some(12));
+    assert_eq!(partial.as_f64(), None);
+
+    let partial = Partial::new("-".to_string(), CommandOptionType::Integer);
+    assert_eq!(
+        parse("-", CommandOptionType::Integer),
+        AutocompleteValue::Focused(partial.clone())
+    );
+    assert_eq!(partial.as_i64(), None);
+    assert_eq!(partial.as_str(), "-");
+
+    let partial = Partial::new("0.5".to_string(), CommandOptionType::Number);
+    assert_eq!(partial.as_f64(), Some(0.5));
+    assert_eq!(partial.kind(), CommandOptionType::Number);
+    assert_eq!(partial.as_str(), "0.5");
+
+    let partial = Partial::new("inf".to_string(), CommandOptionType::Number);
+    assert_eq!(partial.as_f64(), None);
+
+    let partial = Partial::new("page".to_string(), CommandOptionType::String);
+    assert_eq!(partial.kind(), CommandOptionType::String);
+    assert_eq!(partial.as_i64(), None);
+    assert_eq!(partial.into_string(), "page");
+}
+
+#[test]
+fn test_autocomplete_subcommand() {
+    let edit = subcommand_input(
+        "edit",
+        vec![
+            ("content", CommandOptionValue::String("hello".to_string())),
+            (
+                "name",
+                CommandOptionValue::Focused("fa".to_string(), CommandOptionType::String),
+            ),
+        ],
+    );
+    let data = input(vec![(
+        "tag",
+        CommandOptionValue::SubCommandGroup(edit.options),
+    )]);
+
+    assert_eq!(data.focused(), None);
+    assert_eq!(data.focused_path(), Some(vec!["tag", "edit", "name"]));
+
+    let result = ManageAutocomplete::from_interaction(data).unwrap();
+    assert_eq!(
+        result,
+        ManageAutocomplete::Tag(TagAutocomplete::Edit(TagEditAutocomplete {
+            name: AutocompleteValue::Focused(Partial::new(
+                "fa".to_string(),
+                CommandOptionType::String
+            )),
+            content: Some("hello".to_string()),
+        }))
+    );
+
+    let data = subcommand_input(
+        "page",
+        vec![(
+            "page",
+            CommandOptionValue::Focused("3".to_string(), CommandOptionType::Integer),
+        )],
+    );
+
+    assert_eq!(data.focused_path(), Some(vec!["page", "page"]));
+    assert_eq!(
+        TagAutocomplete::from_interaction(data).unwrap(),
+        TagAutocomplete::Page(NumericCommand {
+            page: AutocompleteValue::Focused(Partial::new(
+                "3".to_string(),
+                CommandOptionType::Integer
+            ))
+        })
+    );
+}
+
+#[test]
+fn test_generated_autocomplete_model() {
+    let data = input(vec![
+        ("position", CommandOptionValue::Integer(2)),
+        (
+            "name",
+            CommandOptionValue::Focused("fa".to_string(), CommandOptionType::String),
+        ),
+    ]);
+
+    let model = EditAutocomplete::from_interaction(data).unwrap();
+    assert!(format!("{model:?}").starts_with("EditAutocomplete {"));
+
+    let EditAutocomplete {
+        name,
+        content,
+        position,
+    } = model;
+
+    assert_eq!(
+        name,
+        AutocompleteValue::Focused(Partial::new("fa".to_string(), CommandOptionType::String))
+    );
+    assert_eq!(content, None);
+    assert_eq!(position, Some(2));
+
+    // Field attributes are kept in the autocomplete model
+    let data = string_input(&[("name", "a very long name")]);
+    assert!(EditAutocomplete::from_interaction(data).is_err());
+
+    // The main model is unchanged
+    assert_eq!(EditCommand::create_command().options.len(), 3);
+}
+
+#[test]
+fn test_autocomplete_inline_subcommand() {
+    let data = subcommand_input(
+        "delete",
+        vec![(
+            "name",
+            CommandOptionValue::Focused("ta".to_string(), CommandOptionType::String),
+        )],
+    );
+
+    assert_eq!(
+        TagAutocomplete::from_interaction(data).unwrap(),
+        TagAutocomplete::Delete {
+            name: AutocompleteValue::Focused(Partial::new(
+                "ta".to_string(),
+                CommandOptionType::String
+            ))
+        }
+    );
+}
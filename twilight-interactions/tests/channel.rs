@@ -0,0 +1,151 @@
+mod common;
+
+use std::{borrow::Cow, collections::HashMap};
+
+use twilight_interactions::{
+    command::{
+        CategoryChannel, CommandInputData, CommandModel, CreateCommand, TextChannel, ThreadChannel,
+        VoiceChannel,
+    },
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{
+    application::interaction::{
+        application_command::CommandOptionValue, InteractionChannel, InteractionDataResolved,
+    },
+    channel::ChannelType,
+    guild::Permissions,
+    id::Id,
+};
+
+use common::input;
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "move", desc = "Move messages")]
+struct MoveCommand {
+    /// Destination channel
+    #[command(channel_types = "guild_announcement guild_voice")]
+    text: TextChannel,
+    /// Voice channel
+    voice: Option<VoiceChannel>,
+    /// Thread
+    thread: Option<ThreadChannel>,
+    /// Category
+    category: Option<CategoryChannel>,
+}
+
+fn channel(id: u64, kind: ChannelType) -> InteractionChannel {
+    InteractionChannel {
+        id: Id::new(id),
+        kind,
+        name: format!("channel {id}"),
+        parent_id: None,
+        permissions: Permissions::empty(),
+        thread_metadata: None,
+    }
+}
+
+fn parse(options: &[(&str, InteractionChannel)]) -> Result<MoveCommand, ParseError> {
+    let resolved = InteractionDataResolved {
+        channels: options
+            .iter()
+            .map(|(_, channel)| (channel.id, channel.clone()))
+            .collect(),
+        members: HashMap::new(),
+        roles: HashMap::new(),
+        users: HashMap::new(),
+        messages: HashMap::new(),
+        attachments: HashMap::new(),
+    };
+
+    MoveCommand::from_interaction(CommandInputData {
+        resolved: Some(Cow::Owned(resolved)),
+        ..input(
+            options
+                .iter()
+                .map(|(name, channel)| (*name, CommandOptionValue::Channel(channel.id)))
+                .collect(),
+        )
+    })
+}
+
+#[test]
+fn test_channel_wrappers() {
+    let announcement = channel(1, ChannelType::GuildAnnouncement);
+    let stage = channel(2, ChannelType::GuildStageVoice);
+    let thread = channel(3, ChannelType::PrivateThread);
+    let category = channel(4, ChannelType::GuildCategory);
+
+    assert_eq!(
+        parse(&[
+            ("text", announcement.clone()),
+            ("voice", stage.clone()),
+            ("thread", thread.clone()),
+            ("category", category.clone()),
+        ]),
+        Ok(MoveCommand {
+            text: TextChannel(announcement.clone()),
+            voice: Some(VoiceChannel(stage.clone())),
+            thread: Some(ThreadChannel(thread.clone())),
+            category: Some(CategoryChannel(category)),
+        })
+    );
+
+    assert_eq!(
+        parse(&[("text", announcement), ("voice", thread)]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "voice".into(),
+            kind: ParseOptionErrorType::InvalidChannelType(ChannelType::PrivateThread)
+        }))
+    );
+
+    // The `channel_types` attribute narrows the accepted types
+    for kind in [ChannelType::GuildText, ChannelType::GuildVoice] {
+        assert_eq!(
+            parse(&[("text", channel(5, kind))]),
+            Err(ParseError::Option(ParseOptionError {
+                field: "text".into(),
+                kind: ParseOptionErrorType::InvalidChannelType(kind)
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_channel_wrappers_create_option() {
+    let channel_types = MoveCommand::create_command()
+        .options
+        .into_iter()
+        .map(|option| option.channel_types)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        channel_types,
+        vec![
+            Some(vec![ChannelType::GuildAnnouncement]),
+            Some(vec![ChannelType::GuildVoice, ChannelType::GuildStageVoice]),
+            Some(vec![
+                ChannelType::PublicThread,
+                ChannelType::PrivateThread,
+                ChannelType::AnnouncementThread
+            ]),
+            Some(vec![ChannelType::GuildCategory]),
+        ]
+    );
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "join", desc = "Join a voice channel")]
+struct JoinCommand {
+    /// Channel to join
+    #[command(channel_types = "guild_text")]
+    channel: VoiceChannel,
+}
+
+#[test]
+#[should_panic(
+    expected = "channel types of option `channel` do not include any type accepted by `VoiceChannel`"
+)]
+fn test_channel_wrappers_disjoint_types() {
+    JoinCommand::create_command();
+}
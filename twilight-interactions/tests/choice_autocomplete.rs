@@ -0,0 +1,115 @@
+use twilight_interactions::command::{
+    internal::{CommandOptionData, CreateOptionData},
+    CommandOption, CreateOption, NameLocalizations,
+};
+use twilight_model::application::{
+    command::{CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType},
+    interaction::application_command::CommandOptionValue,
+};
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(int, autocomplete)]
+enum Number {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Eleven,
+    Twelve,
+    Thirteen,
+    Fourteen,
+    Fifteen,
+    Sixteen,
+    Seventeen,
+    Eighteen,
+    Nineteen,
+    Twenty,
+    TwentyOne,
+    TwentyTwo,
+    TwentyThree,
+    TwentyFour,
+    TwentyFive,
+    TwentySix,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(autocomplete)]
+enum Color {
+    #[option(name = "Red", name_localizations = "name_red", value = "red")]
+    Red,
+    #[option(name = "Green", value = "green")]
+    Green,
+}
+
+pub fn name_red() -> NameLocalizations {
+    NameLocalizations::new([("fr", "Rouge")])
+}
+
+fn create_data() -> CreateOptionData {
+    CreateOptionData {
+        name: "name".into(),
+        name_localizations: None,
+        description: "description".into(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    }
+}
+
+#[test]
+fn test_create_option_autocomplete() {
+    let option = Number::create_option(create_data());
+    assert_eq!(option.kind, CommandOptionType::Integer);
+    assert_eq!(option.autocomplete, Some(true));
+    assert_eq!(option.choices, None);
+
+    let option = Color::create_option(create_data());
+    assert_eq!(option.kind, CommandOptionType::String);
+    assert_eq!(option.autocomplete, Some(true));
+    assert_eq!(option.choices, None);
+}
+
+#[test]
+fn test_autocomplete_responder() {
+    assert_eq!(Number::autocomplete("", None).len(), 25);
+
+    let choices = Number::autocomplete("twenty ", None);
+    assert_eq!(choices.len(), 7);
+    assert_eq!(choices[0].name, "Twenty");
+    assert_eq!(choices[0].value, CommandOptionChoiceValue::Integer(19));
+
+    assert_eq!(
+        Color::autocomplete("ROUG", None),
+        vec![CommandOptionChoice {
+            name: "Red".into(),
+            name_localizations: Some([("fr".into(), "Rouge".into())].into()),
+            value: CommandOptionChoiceValue::String("red".into()),
+        }]
+    );
+    assert_eq!(Color::autocomplete("blue", None), Vec::new());
+
+    // Only the names in the given locale are matched
+    assert_eq!(Color::autocomplete("roug", Some("fr"))[0].name, "Red");
+    assert_eq!(Color::autocomplete("red", Some("fr"))[0].name, "Red");
+    assert_eq!(Color::autocomplete("roug", Some("de")), Vec::new());
+}
+
+#[test]
+fn test_autocomplete_parse() {
+    let parsed = Number::from_option(CommandOptionValue::Integer(25), Default::default(), None);
+    assert_eq!(parsed, Ok(Number::TwentySix));
+
+    let parsed = Color::from_option(
+        CommandOptionValue::String("green".into()),
+        Default::default(),
+        None,
+    );
+    assert_eq!(parsed, Ok(Color::Green));
+}
@@ -0,0 +1,124 @@
+use twilight_interactions::command::{internal::CreateOptionData, CommandOption, CreateOption};
+use twilight_model::application::{
+    command::{CommandOptionChoice, CommandOptionChoiceValue},
+    interaction::application_command::CommandOptionValue,
+};
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "snake_case")]
+enum Region {
+    /// North America (US, Canada)
+    NorthAmerica,
+    EuropeWest,
+    #[option(value = "apac")]
+    AsiaPacific,
+    #[option(name = "HTTP test server")]
+    HTTPServer,
+    Backup2Zone,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(int)]
+enum Priority {
+    Low,
+    Normal,
+    #[option(value = 10)]
+    High,
+    Critical,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "kebab-case")]
+enum Kebab {
+    FirstValue,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "camelCase")]
+enum Camel {
+    HTTPServer,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Screaming {
+    FirstValue,
+}
+
+fn choices<T: CreateOption>() -> Vec<(String, CommandOptionChoiceValue)> {
+    let option = T::create_option(CreateOptionData {
+        name: "option".into(),
+        name_localizations: None,
+        description: "Option".into(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: Default::default(),
+    });
+
+    option
+        .choices
+        .unwrap()
+        .into_iter()
+        .map(|CommandOptionChoice { name, value, .. }| (name, value))
+        .collect()
+}
+
+#[test]
+fn test_choice_rename_all() {
+    assert_eq!(
+        choices::<Region>(),
+        vec![
+            (
+                "North America (US, Canada)".into(),
+                CommandOptionChoiceValue::String("north_america".into())
+            ),
+            (
+                "Europe West".into(),
+                CommandOptionChoiceValue::String("europe_west".into())
+            ),
+            (
+                "Asia Pacific".into(),
+                CommandOptionChoiceValue::String("apac".into())
+            ),
+            (
+                "HTTP test server".into(),
+                CommandOptionChoiceValue::String("http_server".into())
+            ),
+            (
+                "Backup2 Zone".into(),
+                CommandOptionChoiceValue::String("backup2_zone".into())
+            ),
+        ]
+    );
+
+    let parsed = Region::from_option(
+        CommandOptionValue::String("europe_west".into()),
+        Default::default(),
+        None,
+    );
+    assert_eq!(parsed, Ok(Region::EuropeWest));
+
+    assert_eq!(Kebab::FirstValue.value(), "first-value");
+    assert_eq!(Camel::HTTPServer.value(), "httpServer");
+    assert_eq!(Screaming::FirstValue.value(), "FIRST_VALUE");
+}
+
+#[test]
+fn test_choice_int() {
+    assert_eq!(
+        [
+            Priority::Low,
+            Priority::Normal,
+            Priority::High,
+            Priority::Critical
+        ]
+        .map(|priority| priority.value()),
+        [0, 1, 10, 11]
+    );
+
+    assert_eq!(
+        choices::<Priority>()[1],
+        ("Normal".into(), CommandOptionChoiceValue::Integer(1))
+    );
+}
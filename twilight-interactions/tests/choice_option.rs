@@ -1,8 +1,13 @@
-use std::collections::HashMap;
+mod common;
 
-use twilight_interactions::command::{
-    internal::{CommandOptionData, CreateOptionData},
-    CommandOption, CreateOption, NameLocalizations,
+use std::{collections::HashMap, fmt};
+
+use twilight_interactions::{
+    command::{
+        internal::{CommandOptionData, CreateOptionData},
+        CommandOption, CreateOption, NameLocalizations,
+    },
+    error::ParseOptionErrorType,
 };
 use twilight_model::application::{
     command::{
@@ -12,7 +17,10 @@ use twilight_model::application::{
     interaction::application_command::CommandOptionValue,
 };
 
+use common::option;
+
 #[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(helpers)]
 enum ChoiceString {
     #[option(name = "Dog", name_localizations = "name_dog", value = "dog")]
     Dog,
@@ -23,6 +31,7 @@ enum ChoiceString {
 }
 
 #[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(helpers)]
 enum ChoiceInt {
     #[option(name = "One", value = 1)]
     One,
@@ -33,6 +42,7 @@ enum ChoiceInt {
 }
 
 #[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(helpers)]
 enum ChoiceNumber {
     #[option(name = "One", value = 1.0)]
     One,
@@ -76,8 +86,6 @@ fn test_command_option_string() {
     };
 
     let command_option = TwilightCommandOption {
-        autocomplete: Some(false),
-        channel_types: None,
         choices: Some(vec![
             CommandOptionChoice {
                 name: "Dog".to_string(),
@@ -95,17 +103,7 @@ fn test_command_option_string() {
                 value: CommandOptionChoiceValue::String("crab".to_string()),
             },
         ]),
-        description: "description".to_string(),
-        description_localizations: None,
-        kind: CommandOptionType::String,
-        max_length: None,
-        max_value: None,
-        min_length: None,
-        min_value: None,
-        name: "name".to_string(),
-        name_localizations: None,
-        options: None,
-        required: Some(false),
+        ..option("name", "description", CommandOptionType::String, false)
     };
 
     assert_eq!(command_option, ChoiceString::create_option(create_data))
@@ -141,8 +139,6 @@ fn test_command_option_integer() {
     };
 
     let command_option = TwilightCommandOption {
-        autocomplete: Some(false),
-        channel_types: None,
         choices: Some(vec![
             CommandOptionChoice {
                 name: "One".to_string(),
@@ -160,17 +156,7 @@ fn test_command_option_integer() {
                 value: CommandOptionChoiceValue::Integer(3),
             },
         ]),
-        description: "description".to_string(),
-        description_localizations: None,
-        kind: CommandOptionType::Integer,
-        max_length: None,
-        max_value: None,
-        min_length: None,
-        min_value: None,
-        name: "name".to_string(),
-        name_localizations: None,
-        options: None,
-        required: Some(false),
+        ..option("name", "description", CommandOptionType::Integer, false)
     };
 
     assert_eq!(command_option, ChoiceInt::create_option(create_data));
@@ -206,8 +192,6 @@ fn test_command_option_number() {
     };
 
     let command_option = TwilightCommandOption {
-        autocomplete: Some(false),
-        channel_types: None,
         choices: Some(vec![
             CommandOptionChoice {
                 name: "One".to_string(),
@@ -225,18 +209,128 @@ fn test_command_option_number() {
                 value: CommandOptionChoiceValue::Number(0.25),
             },
         ]),
-        description: "description".to_string(),
-        description_localizations: None,
-        kind: CommandOptionType::Number,
-        max_length: None,
-        max_value: None,
-        min_length: None,
-        min_value: None,
-        name: "name".to_string(),
-        name_localizations: None,
-        options: None,
-        required: Some(false),
+        ..option("name", "description", CommandOptionType::Number, false)
     };
 
     assert_eq!(command_option, ChoiceNumber::create_option(create_data));
 }
+
+#[derive(CommandOption, CreateOption, Debug, Clone, PartialEq, Eq)]
+#[option(helpers)]
+enum ChoiceOther {
+    #[option(name = "Dog", value = "dog")]
+    Dog,
+    #[option(other)]
+    Other(String),
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, PartialEq, Eq)]
+#[option(helpers)]
+enum ChoiceOtherInt {
+    #[option(name = "One", value = 1)]
+    One,
+    #[option(other)]
+    Unknown(i64),
+}
+
+#[test]
+fn test_command_option_other() {
+    let parse = |value: &str| {
+        ChoiceOther::from_option(
+            CommandOptionValue::String(value.into()),
+            CommandOptionData::default(),
+            None,
+        )
+    };
+
+    assert_eq!(parse("dog"), Ok(ChoiceOther::Dog));
+    assert_eq!(parse("cat"), Ok(ChoiceOther::Other("cat".into())));
+    assert_eq!(ChoiceOther::Other("cat".into()).value(), "cat");
+    assert_eq!(ChoiceOther::Dog.value(), "dog");
+    assert_eq!(ChoiceOther::Other("cat".into()).other_value(), Some("cat"));
+    assert_eq!(ChoiceOther::Dog.other_value(), None);
+
+    let parsed =
+        ChoiceOtherInt::from_option(CommandOptionValue::Integer(5), Default::default(), None);
+    assert_eq!(parsed, Ok(ChoiceOtherInt::Unknown(5)));
+    assert_eq!(ChoiceOtherInt::Unknown(5).value(), 5);
+    assert_eq!(ChoiceOtherInt::Unknown(5).other_value(), Some(5));
+
+    let create_data = CreateOptionData {
+        name: "name".into(),
+        name_localizations: None,
+        description: "description".into(),
+        description_localizations: None,
+        required: None,
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+
+    assert_eq!(
+        ChoiceOther::create_option(create_data).choices,
+        Some(vec![CommandOptionChoice {
+            name: "Dog".into(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String("dog".into()),
+        }])
+    );
+}
+
+#[test]
+fn test_choice_helpers() {
+    assert_eq!(
+        ChoiceString::ALL,
+        &[ChoiceString::Dog, ChoiceString::Cat, ChoiceString::Crab]
+    );
+    assert_eq!(ChoiceOther::ALL, &[ChoiceOther::Dog]);
+
+    assert_eq!(ChoiceString::Crab.name(), "Crab");
+    assert_eq!(ChoiceOtherInt::Unknown(5).name(), "Unknown");
+    assert_eq!(ChoiceString::Dog.localized_name("en"), "Dog");
+    assert_eq!(ChoiceString::Dog.localized_name("fr"), "Dog");
+    assert_eq!(ChoiceString::Cat.localized_name("en"), "Cat");
+
+    assert_eq!("cat".parse(), Ok(ChoiceString::Cat));
+    assert_eq!(" 2 ".parse(), Ok(ChoiceInt::Two));
+    assert_eq!("0.5".parse(), Ok(ChoiceNumber::Half));
+    assert_eq!("bird".parse(), Ok(ChoiceOther::Other("bird".into())));
+    assert_eq!(
+        "two".parse::<ChoiceInt>(),
+        Err(ParseOptionErrorType::InvalidChoice("two".into()))
+    );
+    assert_eq!(
+        "4".parse::<ChoiceInt>(),
+        Err(ParseOptionErrorType::InvalidChoice("4".into()))
+    );
+
+    assert_eq!(ChoiceNumber::Quarter.to_string(), "Quarter");
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+enum ChoiceCustomDisplay {
+    #[option(name = "Small", value = "s")]
+    Small,
+    #[option(name = "Large", value = "l")]
+    Large,
+}
+
+impl ChoiceCustomDisplay {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Large => "large",
+        }
+    }
+}
+
+impl fmt::Display for ChoiceCustomDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "size {}", self.name())
+    }
+}
+
+#[test]
+fn test_choice_without_helpers() {
+    assert_eq!(ChoiceCustomDisplay::Small.value(), "s");
+    assert_eq!(ChoiceCustomDisplay::Large.to_string(), "size large");
+}
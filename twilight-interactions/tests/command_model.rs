@@ -1,19 +1,25 @@
+mod common;
+
 use std::{borrow::Cow, collections::HashMap};
 
-use twilight_interactions::command::{
-    CommandInputData, CommandModel, CommandOption, ResolvedMentionable, ResolvedUser,
+use twilight_interactions::{
+    command::{
+        CommandInputData, CommandModel, CommandOption, OptionConstraint, ResolvedMentionable,
+        ResolvedUser,
+    },
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
 };
 use twilight_model::{
     application::interaction::{
-        application_command::{CommandDataOption, CommandOptionValue},
-        InteractionDataResolved, InteractionMember,
+        application_command::CommandOptionValue, InteractionDataResolved, InteractionMember,
     },
     guild::{MemberFlags, Permissions},
     id::Id,
-    user::User,
     util::Timestamp,
 };
 
+use common::{input, string_input, user};
+
 #[derive(CommandModel, Debug, PartialEq, Eq)]
 struct DemoCommand<'a, T>
 where
@@ -31,34 +37,55 @@ where
 #[derive(CommandModel, Debug, PartialEq, Eq)]
 struct UnitCommand;
 
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct ValidateCommand {
+    #[command(validate = "validate_color")]
+    color: String,
+    #[command(validate = "validate_even")]
+    number: Option<i64>,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(one_of = "user role")]
+struct ConstraintsCommand {
+    user: Option<i64>,
+    role: Option<i64>,
+    #[command(rename = "time", conflicts_with = "until")]
+    duration: Option<i64>,
+    #[command(requires = "user")]
+    until: Option<i64>,
+    #[command(required_unless = "duration until")]
+    reason: Option<String>,
+}
+
+fn validate_color(value: &str) -> Result<(), &'static str> {
+    match value.strip_prefix('#') {
+        Some(hex) if hex.len() == 6 && u32::from_str_radix(hex, 16).is_ok() => Ok(()),
+        _ => Err("must be a valid hex color"),
+    }
+}
+
+fn validate_even(value: &i64) -> Result<(), String> {
+    if value % 2 == 0 {
+        Ok(())
+    } else {
+        Err(format!("{value} is not even"))
+    }
+}
+
 #[test]
 fn test_command_model() {
     let user_id = Id::new(123);
     let options = vec![
-        CommandDataOption {
-            name: "member".to_string(),
-            value: CommandOptionValue::User(user_id),
-        },
-        CommandDataOption {
-            name: "text".into(),
-            value: CommandOptionValue::String("hello world".into()),
-        },
-        CommandDataOption {
-            name: "number".into(),
-            value: CommandOptionValue::Integer(42),
-        },
-        CommandDataOption {
-            name: "generic".into(),
-            value: CommandOptionValue::Integer(0),
-        },
-        CommandDataOption {
-            name: "cow".into(),
-            value: CommandOptionValue::String("cow".into()),
-        },
-        CommandDataOption {
-            name: "mentionable".into(),
-            value: CommandOptionValue::Mentionable(user_id.cast()),
-        },
+        ("member", CommandOptionValue::User(user_id)),
+        ("text", CommandOptionValue::String("hello world".into())),
+        ("number", CommandOptionValue::Integer(42)),
+        ("generic", CommandOptionValue::Integer(0)),
+        ("cow", CommandOptionValue::String("cow".into())),
+        (
+            "mentionable",
+            CommandOptionValue::Mentionable(user_id.cast()),
+        ),
     ];
 
     let member = InteractionMember {
@@ -75,27 +102,7 @@ fn test_command_model() {
         banner: None,
     };
 
-    let user = User {
-        avatar: None,
-        bot: false,
-        discriminator: 1,
-        email: None,
-        flags: None,
-        id: user_id,
-        locale: None,
-        mfa_enabled: None,
-        name: "someone".into(),
-        premium_type: None,
-        public_flags: None,
-        system: None,
-        verified: None,
-        accent_color: None,
-        banner: None,
-        avatar_decoration: None,
-        global_name: None,
-        avatar_decoration_data: None,
-        primary_guild: None,
-    };
+    let user = user(user_id, "someone");
 
     let resolved_user = ResolvedUser {
         resolved: user.clone(),
@@ -112,8 +119,8 @@ fn test_command_model() {
     };
 
     let data = CommandInputData {
-        options,
         resolved: Some(Cow::Owned(resolved)),
+        ..input(options)
     };
 
     let result = DemoCommand::from_interaction(data).unwrap();
@@ -133,12 +140,178 @@ fn test_command_model() {
 
 #[test]
 fn test_unit_command_model() {
-    let data = CommandInputData {
-        options: vec![],
-        resolved: None,
-    };
+    let data = input(Vec::new());
 
     let result = UnitCommand::from_interaction(data).unwrap();
 
     assert_eq!(UnitCommand, result);
 }
+
+#[test]
+fn test_command_model_validate() {
+    let data = input(vec![
+        ("color", CommandOptionValue::String("#ff00ff".into())),
+        ("number", CommandOptionValue::Integer(42)),
+    ]);
+
+    let result = ValidateCommand::from_interaction(data).unwrap();
+
+    assert_eq!(
+        ValidateCommand {
+            color: "#ff00ff".into(),
+            number: Some(42)
+        },
+        result
+    );
+
+    let data = input(vec![
+        ("color", CommandOptionValue::String("#ff00ff".into())),
+        ("number", CommandOptionValue::Integer(3)),
+    ]);
+
+    let result = ValidateCommand::from_interaction(data);
+
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "number".into(),
+            kind: ParseOptionErrorType::Custom("3 is not even".into())
+        }))
+    );
+
+    let data = string_input(&[("color", "purple")]);
+
+    let error = ValidateCommand::from_interaction(data).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "failed to parse option `color`: must be a valid hex color"
+    );
+}
+
+#[test]
+fn test_command_model_constraints() {
+    fn parse(options: &[(&str, CommandOptionValue)]) -> Result<ConstraintsCommand, ParseError> {
+        ConstraintsCommand::from_interaction(input(options.to_vec()))
+    }
+
+    assert_eq!(
+        parse(&[
+            ("user", CommandOptionValue::Integer(1)),
+            ("until", CommandOptionValue::Integer(2)),
+        ]),
+        Ok(ConstraintsCommand {
+            user: Some(1),
+            role: None,
+            duration: None,
+            until: Some(2),
+            reason: None,
+        })
+    );
+    assert_eq!(
+        parse(&[("reason", CommandOptionValue::String("spam".into()))]),
+        Err(ParseError::MissingOneOf(vec!["user".into(), "role".into()]))
+    );
+    assert_eq!(
+        parse(&[("role", CommandOptionValue::Integer(1))]),
+        Err(ParseError::MissingOneOf(vec![
+            "reason".into(),
+            "time".into(),
+            "until".into()
+        ]))
+    );
+    assert_eq!(
+        parse(&[
+            ("role", CommandOptionValue::Integer(1)),
+            ("until", CommandOptionValue::Integer(2)),
+        ]),
+        Err(ParseError::MissingRequirement {
+            field: "until".into(),
+            requires: "user".into()
+        })
+    );
+    assert_eq!(
+        parse(&[
+            ("user", CommandOptionValue::Integer(1)),
+            ("time", CommandOptionValue::Integer(2)),
+            ("until", CommandOptionValue::Integer(3)),
+        ]),
+        Err(ParseError::Conflict {
+            field: "time".into(),
+            conflicts_with: "until".into()
+        })
+    );
+
+    assert_eq!(
+        ConstraintsCommand::CONSTRAINTS,
+        &[
+            OptionConstraint::ConflictsWith {
+                field: "time",
+                conflicts_with: "until"
+            },
+            OptionConstraint::Requires {
+                field: "until",
+                requires: "user"
+            },
+            OptionConstraint::RequiredUnless {
+                field: "reason",
+                alternatives: &["time", "until"]
+            },
+            OptionConstraint::OneOf(&["user", "role"]),
+        ]
+    );
+}
+
+#[test]
+fn test_command_model_integers() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct IntegerCommand {
+        byte: u8,
+        count: Option<std::num::NonZeroU32>,
+    }
+
+    let parse = |byte: i64, count: i64| {
+        IntegerCommand::from_interaction(input(vec![
+            ("byte", CommandOptionValue::Integer(byte)),
+            ("count", CommandOptionValue::Integer(count)),
+        ]))
+    };
+
+    assert_eq!(
+        parse(255, 1),
+        Ok(IntegerCommand {
+            byte: 255,
+            count: std::num::NonZeroU32::new(1)
+        })
+    );
+    assert_eq!(
+        parse(256, 1),
+        Err(ParseError::Option(ParseOptionError {
+            field: "byte".into(),
+            kind: ParseOptionErrorType::IntegerOutOfRange(256)
+        }))
+    );
+    assert_eq!(
+        parse(0, 0),
+        Err(ParseError::Option(ParseOptionError {
+            field: "count".into(),
+            kind: ParseOptionErrorType::IntegerOutOfRange(0)
+        }))
+    );
+}
+
+#[test]
+fn test_command_model_f32() {
+    let parse =
+        |value: f64| f32::from_option(CommandOptionValue::Number(value), Default::default(), None);
+
+    assert_eq!(parse(0.5), Ok(0.5));
+    assert_eq!(
+        parse(1e300),
+        Err(ParseOptionErrorType::NumberOutOfRange(1e300))
+    );
+    assert_eq!(
+        parse(-1e300),
+        Err(ParseOptionErrorType::NumberOutOfRange(-1e300))
+    );
+}
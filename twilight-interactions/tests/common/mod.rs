@@ -0,0 +1,98 @@
+//! Helpers shared by integration tests.
+
+#![allow(dead_code)]
+
+use twilight_interactions::command::CommandInputData;
+use twilight_model::{
+    application::{
+        command::{CommandOption, CommandOptionType},
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    },
+    id::{marker::UserMarker, Id},
+    user::User,
+};
+
+/// Registered option without choices or constraints.
+pub fn option(
+    name: &str,
+    description: &str,
+    kind: CommandOptionType,
+    required: bool,
+) -> CommandOption {
+    CommandOption {
+        autocomplete: Some(false),
+        channel_types: None,
+        choices: None,
+        description: description.into(),
+        description_localizations: None,
+        kind,
+        max_length: None,
+        max_value: None,
+        min_length: None,
+        min_value: None,
+        name: name.into(),
+        name_localizations: None,
+        options: None,
+        required: Some(required),
+    }
+}
+
+/// Command data with the given options and no resolved data.
+pub fn input(options: Vec<(&str, CommandOptionValue)>) -> CommandInputData<'static> {
+    CommandInputData {
+        options: options
+            .into_iter()
+            .map(|(name, value)| CommandDataOption {
+                name: name.into(),
+                value,
+            })
+            .collect(),
+        resolved: None,
+    }
+}
+
+/// Command data with the given `STRING` options and no resolved data.
+pub fn string_input(options: &[(&str, &str)]) -> CommandInputData<'static> {
+    input(
+        options
+            .iter()
+            .map(|(name, value)| (*name, CommandOptionValue::String((*value).into())))
+            .collect(),
+    )
+}
+
+/// User with the given ID and name.
+pub fn user(id: Id<UserMarker>, name: &str) -> User {
+    User {
+        avatar: None,
+        bot: false,
+        discriminator: 1,
+        email: None,
+        flags: None,
+        id,
+        locale: None,
+        mfa_enabled: None,
+        name: name.into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+        accent_color: None,
+        banner: None,
+        avatar_decoration: None,
+        global_name: None,
+        avatar_decoration_data: None,
+        primary_guild: None,
+    }
+}
+
+/// Command data invoking a subcommand with the given options.
+pub fn subcommand_input(
+    name: &str,
+    options: Vec<(&str, CommandOptionValue)>,
+) -> CommandInputData<'static> {
+    input(vec![(
+        name,
+        CommandOptionValue::SubCommand(input(options).options),
+    )])
+}
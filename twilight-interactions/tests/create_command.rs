@@ -1,4 +1,6 @@
-use std::{borrow::Cow, collections::HashMap};
+mod common;
+
+use std::{borrow::Cow, collections::HashMap, num::NonZeroU64};
 
 use twilight_interactions::command::{
     ApplicationCommandData, CreateCommand, CreateOption, DescLocalizations, NameLocalizations,
@@ -14,6 +16,8 @@ use twilight_model::{
     oauth::ApplicationIntegrationType,
 };
 
+use common::option;
+
 /// Demo command for testing purposes
 #[derive(CreateCommand, Debug, PartialEq)]
 #[command(
@@ -65,105 +69,68 @@ fn demo_desc() -> DescLocalizations {
 #[command(name = "unit", desc = "Unit command for testing purposes")]
 struct UnitCommand;
 
+#[derive(CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "integers", desc = "Integer options")]
+struct IntegerCommand {
+    /// A byte
+    byte: u8,
+    /// A restricted number
+    #[command(min_value = -10, max_value = 100_000)]
+    restricted: i16,
+    /// A large number
+    large: NonZeroU64,
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "floats", desc = "Float options")]
+struct FloatCommand {
+    /// A ratio
+    ratio: f32,
+    /// A scale
+    #[command(min_value = 0.5)]
+    scale: f32,
+}
+
+#[derive(CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "inverted", desc = "Inverted bounds")]
+struct InvertedCommand {
+    /// A byte
+    #[command(min_value = 300)]
+    byte: u8,
+}
+
 #[test]
 fn test_create_command() {
     let options = vec![
         CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "A member".into(),
             description_localizations: Some(HashMap::from([("fr".into(), "Un membre".into())])),
-            kind: CommandOptionType::User,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "member".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(true),
+            ..option("member", "A member", CommandOptionType::User, true)
         },
         CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Some text".into(),
-            description_localizations: None,
-            kind: CommandOptionType::String,
-            max_length: None,
-            max_value: None,
             min_length: Some(5),
-            min_value: None,
-            name: "text".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(true),
+            ..option("text", "Some text", CommandOptionType::String, true)
         },
         CommandOption {
             autocomplete: Some(true),
-            channel_types: None,
-            choices: None,
-            description: "A number".into(),
-            description_localizations: None,
-            kind: CommandOptionType::Number,
-            max_length: None,
             max_value: Some(CommandOptionValue::Number(50.0)),
-            min_length: None,
-            min_value: None,
-            name: "number".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(true),
+            ..option("number", "A number", CommandOptionType::Number, true)
         },
         CommandOption {
-            autocomplete: Some(false),
             channel_types: Some(vec![ChannelType::GuildText, ChannelType::Private]),
-            choices: None,
-            description: "A text channel".into(),
-            description_localizations: None,
-            kind: CommandOptionType::Channel,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "channel".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(false),
-        },
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Generic field".into(),
-            description_localizations: None,
-            kind: CommandOptionType::Integer,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "generic".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(false),
-        },
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "More text".into(),
-            description_localizations: None,
-            kind: CommandOptionType::String,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "cow".into(),
-            name_localizations: None,
-            options: None,
-            required: Some(false),
+            ..option(
+                "channel",
+                "A text channel",
+                CommandOptionType::Channel,
+                false,
+            )
         },
+        option(
+            "generic",
+            "Generic field",
+            CommandOptionType::Integer,
+            false,
+        ),
+        option("cow", "More text", CommandOptionType::String, false),
     ];
 
     let name_localizations = HashMap::from([("en".into(), "demo".into())]);
@@ -210,3 +177,42 @@ fn test_unit_create_command() {
     assert_eq!(UnitCommand::create_command(), expected);
     assert_eq!(UnitCommand::NAME, "unit");
 }
+
+#[test]
+fn test_create_integer_options() {
+    let integer = |name: &str, description: &str, min: i64, max: i64| CommandOption {
+        max_value: Some(CommandOptionValue::Integer(max)),
+        min_value: Some(CommandOptionValue::Integer(min)),
+        ..option(name, description, CommandOptionType::Integer, true)
+    };
+
+    let options = vec![
+        integer("byte", "A byte", 0, 255),
+        integer("restricted", "A restricted number", -10, 32767),
+        integer("large", "A large number", 1, 1 << 53),
+    ];
+
+    assert_eq!(IntegerCommand::create_command().options, options);
+}
+
+#[test]
+fn test_create_float_options() {
+    let number = |name: &str, description: &str, min: f64| CommandOption {
+        max_value: Some(CommandOptionValue::Number(f32::MAX.into())),
+        min_value: Some(CommandOptionValue::Number(min)),
+        ..option(name, description, CommandOptionType::Number, true)
+    };
+
+    let options = vec![
+        number("ratio", "A ratio", f32::MIN.into()),
+        number("scale", "A scale", 0.5),
+    ];
+
+    assert_eq!(FloatCommand::create_command().options, options);
+}
+
+#[test]
+#[should_panic(expected = "leave no value in the range of its type (0 to 255)")]
+fn test_create_inverted_integer_bounds() {
+    InvertedCommand::create_command();
+}
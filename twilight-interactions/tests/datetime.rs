@@ -0,0 +1,119 @@
+#![cfg(all(feature = "chrono", feature = "time"))]
+
+mod common;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use time::OffsetDateTime;
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::command::CommandOptionType;
+
+use common::{option, string_input};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "event", desc = "Schedule an event")]
+struct EventCommand {
+    /// Start of the event
+    start: DateTime<Utc>,
+    /// End of the event
+    #[command(formats = "timestamp")]
+    end: Option<OffsetDateTime>,
+    /// Day of the event
+    day: Option<NaiveDate>,
+}
+
+fn parse(options: &[(&str, &str)]) -> Result<EventCommand, ParseError> {
+    EventCommand::from_interaction(string_input(options))
+}
+
+#[test]
+fn test_datetime_formats() {
+    let expected = DateTime::from_timestamp(1714581000, 0).unwrap();
+
+    for input in [
+        "2024-05-01T18:30:00+02:00",
+        "2024-05-01T16:30:00Z",
+        "2024-05-01 16:30",
+        "<t:1714581000>",
+        "<t:1714581000:R>",
+    ] {
+        assert_eq!(parse(&[("start", input)]).unwrap().start, expected);
+    }
+
+    assert_eq!(
+        parse(&[("start", "2024-05-01")]).unwrap().start,
+        DateTime::from_timestamp(1714521600, 0).unwrap()
+    );
+    assert_eq!(
+        parse(&[("start", "2024-05-01"), ("day", "2024-05-01 16:30")])
+            .unwrap()
+            .day,
+        NaiveDate::from_ymd_opt(2024, 5, 1)
+    );
+
+    // Dates are taken in the offset of the value
+    assert_eq!(
+        parse(&[
+            ("start", "2024-05-01"),
+            ("day", "2024-05-01T00:30:00+02:00")
+        ])
+        .unwrap()
+        .day,
+        NaiveDate::from_ymd_opt(2024, 5, 1)
+    );
+    assert_eq!(
+        parse(&[
+            ("start", "2024-05-01"),
+            ("day", "2024-04-30T23:30:00-02:00")
+        ])
+        .unwrap()
+        .day,
+        NaiveDate::from_ymd_opt(2024, 4, 30)
+    );
+}
+
+#[test]
+fn test_datetime_restricted_formats() {
+    let command = parse(&[("start", "2024-05-01"), ("end", "<t:1714581000:f>")]).unwrap();
+    assert_eq!(
+        command.end,
+        Some(OffsetDateTime::from_unix_timestamp(1714581000).unwrap())
+    );
+
+    assert_eq!(
+        parse(&[("start", "2024-05-01"), ("end", "2024-05-01 16:30")]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "end".into(),
+            kind: ParseOptionErrorType::InvalidDateTime("2024-05-01 16:30".into())
+        }))
+    );
+}
+
+#[test]
+fn test_datetime_invalid() {
+    for input in ["tomorrow", "2024-13-01", "<t:abc>", "<t:1714581000:X>"] {
+        assert_eq!(
+            parse(&[("start", input)]),
+            Err(ParseError::Option(ParseOptionError {
+                field: "start".into(),
+                kind: ParseOptionErrorType::InvalidDateTime(input.into())
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_datetime_create_option() {
+    let kind = CommandOptionType::String;
+
+    assert_eq!(
+        EventCommand::create_command().options,
+        vec![
+            option("start", "Start of the event", kind, true),
+            option("end", "End of the event", kind, false),
+            option("day", "Day of the event", kind, false),
+        ]
+    );
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use std::time::Duration;
+
+use twilight_interactions::{
+    command::{AutocompleteValue, CommandModel, CreateCommand, HumanDuration, Partial},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::{
+    command::CommandOptionType, interaction::application_command::CommandOptionValue,
+};
+
+use common::{input, option, string_input};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "timeout", desc = "Timeout a member")]
+struct TimeoutCommand {
+    /// Duration of the timeout
+    #[command(min_value = 60, max_value = 2419200)]
+    duration: HumanDuration,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+struct TimeoutAutocomplete {
+    duration: AutocompleteValue<HumanDuration>,
+}
+
+#[test]
+fn test_parse_duration() {
+    let parse = |input: &str| input.parse::<HumanDuration>().map(|d| d.0.as_secs());
+
+    assert_eq!(parse("90s"), Ok(90));
+    assert_eq!(parse("1h30m"), Ok(5400));
+    assert_eq!(parse("2d"), Ok(172800));
+    assert_eq!(parse(" 1 week 2 Days 3hrs "), Ok(788400));
+    assert_eq!(parse("0s"), Ok(0));
+
+    for invalid in [
+        "",
+        "10",
+        "h",
+        "1h30",
+        "1y",
+        "1h,30m",
+        "99999999999999999999s",
+    ] {
+        assert_eq!(
+            parse(invalid),
+            Err(ParseOptionErrorType::InvalidDuration(invalid.into()))
+        );
+    }
+}
+
+#[test]
+fn test_display_duration() {
+    let display = |secs: u64| HumanDuration(Duration::from_secs(secs)).to_string();
+
+    assert_eq!(display(0), "0s");
+    assert_eq!(display(90), "1m30s");
+    assert_eq!(display(5400), "1h30m");
+    assert_eq!(display(788400), "1w2d3h");
+}
+
+#[test]
+fn test_duration_command_model() {
+    let parse =
+        |value: &str| TimeoutCommand::from_interaction(string_input(&[("duration", value)]));
+
+    assert_eq!(
+        parse("1h"),
+        Ok(TimeoutCommand {
+            duration: HumanDuration(Duration::from_secs(3600))
+        })
+    );
+    assert_eq!(
+        parse("30s"),
+        Err(ParseError::Option(ParseOptionError {
+            field: "duration".into(),
+            kind: ParseOptionErrorType::DurationOutOfRange(Duration::from_secs(30))
+        }))
+    );
+    assert_eq!(
+        parse("soon"),
+        Err(ParseError::Option(ParseOptionError {
+            field: "duration".into(),
+            kind: ParseOptionErrorType::InvalidDuration("soon".into())
+        }))
+    );
+
+    let autocomplete = TimeoutAutocomplete::from_interaction(input(vec![(
+        "duration",
+        CommandOptionValue::Focused("1h 90m".into(), CommandOptionType::String),
+    )]))
+    .unwrap();
+
+    assert_eq!(
+        autocomplete.duration,
+        AutocompleteValue::Focused(Partial::new("1h 90m".into(), CommandOptionType::String))
+    );
+
+    let AutocompleteValue::Focused(focused) = autocomplete.duration else {
+        panic!("expected focused value");
+    };
+    assert_eq!(
+        focused
+            .as_str()
+            .parse::<HumanDuration>()
+            .unwrap()
+            .to_string(),
+        "2h30m"
+    );
+}
+
+#[test]
+fn test_duration_create_option() {
+    let expected = vec![option(
+        "duration",
+        "Duration of the timeout",
+        CommandOptionType::String,
+        true,
+    )];
+
+    assert_eq!(TimeoutCommand::create_command().options, expected);
+}
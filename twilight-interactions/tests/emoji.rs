@@ -0,0 +1,109 @@
+mod common;
+
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand, EmojiInput},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{
+    application::command::CommandOptionType, channel::message::EmojiReactionType, id::Id,
+};
+
+use common::string_input;
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "react", desc = "Add a reaction role")]
+struct ReactCommand {
+    /// Emoji of the reaction
+    emoji: EmojiInput,
+}
+
+#[test]
+fn test_parse_custom_emoji() {
+    assert_eq!(
+        "<:blob:123>".parse(),
+        Ok(EmojiInput::Custom {
+            id: Id::new(123),
+            name: "blob".into(),
+            animated: false,
+        })
+    );
+    assert_eq!(
+        " <a:party_time:456> ".parse(),
+        Ok(EmojiInput::Custom {
+            id: Id::new(456),
+            name: "party_time".into(),
+            animated: true,
+        })
+    );
+}
+
+#[test]
+fn test_parse_unicode_emoji() {
+    for emoji in ["👍", "👍🏽", "❤️", "1️⃣", "🇫🇷", "👩‍💻", "🏳️‍🌈", "👨‍👩‍👧‍👦", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"]
+    {
+        assert_eq!(emoji.parse(), Ok(EmojiInput::Unicode(emoji.into())));
+    }
+}
+
+#[test]
+fn test_parse_invalid_emoji() {
+    for input in [
+        "",
+        "hello",
+        "1",
+        "👍👍",
+        "🇫",
+        "👩‍",
+        "<:b:123>",
+        "<:blob:0>",
+        "<:blob:abc>",
+        "<b:blob:123>",
+        ":blob:",
+    ] {
+        assert_eq!(
+            input.parse::<EmojiInput>(),
+            Err(ParseOptionErrorType::InvalidEmoji(input.into()))
+        );
+    }
+}
+
+#[test]
+fn test_emoji_display() {
+    let emoji = EmojiInput::Custom {
+        id: Id::new(456),
+        name: "party".into(),
+        animated: true,
+    };
+
+    assert_eq!(emoji.to_string(), "<a:party:456>");
+    assert_eq!(
+        EmojiReactionType::from(emoji),
+        EmojiReactionType::Custom {
+            animated: true,
+            id: Id::new(456),
+            name: Some("party".into())
+        }
+    );
+}
+
+#[test]
+fn test_emoji_command() {
+    let parse = |value: &str| ReactCommand::from_interaction(string_input(&[("emoji", value)]));
+
+    assert_eq!(
+        parse("⭐"),
+        Ok(ReactCommand {
+            emoji: EmojiInput::Unicode("⭐".into())
+        })
+    );
+    assert_eq!(
+        parse("star"),
+        Err(ParseError::Option(ParseOptionError {
+            field: "emoji".into(),
+            kind: ParseOptionErrorType::InvalidEmoji("star".into())
+        }))
+    );
+
+    let options = ReactCommand::create_command().options;
+    assert_eq!(options[0].kind, CommandOptionType::String);
+}
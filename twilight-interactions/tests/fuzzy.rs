@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use twilight_interactions::command::FuzzyMatcher;
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+fn names(choices: Vec<CommandOptionChoice>) -> Vec<String> {
+    choices.into_iter().map(|choice| choice.name).collect()
+}
+
+#[test]
+fn test_fuzzy_ranking() {
+    let matcher = FuzzyMatcher::from_strings(["Banana", "Orange", "Cherry", "Grape", "Mango"]);
+
+    // Prefix matches first, then substrings by position, then typos
+    assert_eq!(names(matcher.matches("an")), ["Banana", "Mango", "Orange"]);
+    assert_eq!(names(matcher.matches("gr")), ["Grape"]);
+    assert_eq!(names(matcher.matches("ornage")), ["Orange"]);
+    assert_eq!(names(matcher.matches("chery")), ["Cherry"]);
+    assert_eq!(names(matcher.matches("kiwi")), Vec::<String>::new());
+
+    // Empty input keeps the original order
+    assert_eq!(
+        names(matcher.matches("")),
+        ["Banana", "Orange", "Cherry", "Grape", "Mango"]
+    );
+}
+
+#[test]
+fn test_fuzzy_stable_ties() {
+    let matcher = FuzzyMatcher::from_strings(["Mars", "Mercury", "Saturn", "Moon"]);
+
+    assert_eq!(names(matcher.matches("m")), ["Mars", "Mercury", "Moon"]);
+    assert_eq!(names(matcher.matches("ur")), ["Saturn", "Mercury"]);
+}
+
+#[test]
+fn test_fuzzy_folding() {
+    let matcher = FuzzyMatcher::from_strings(["Élève", "Straße", "Zürich"]);
+
+    assert_eq!(names(matcher.matches("ELEVE")), ["Élève"]);
+    assert_eq!(names(matcher.matches("strasse")), ["Straße"]);
+    assert_eq!(names(matcher.matches("zur")), ["Zürich"]);
+}
+
+#[test]
+fn test_fuzzy_limit() {
+    let matcher = FuzzyMatcher::from_strings((0..40).map(|i| format!("Item {i}")));
+    let choices = matcher.matches("item");
+
+    assert_eq!(choices.len(), 25);
+    assert_eq!(choices[0].name, "Item 0");
+    assert_eq!(
+        choices[0].value,
+        CommandOptionChoiceValue::String("Item 0".into())
+    );
+}
+
+#[test]
+fn test_fuzzy_locale() {
+    let choice = |name: &str, fr: &str, value| CommandOptionChoice {
+        name: name.into(),
+        name_localizations: Some(HashMap::from([("fr".into(), fr.into())])),
+        value: CommandOptionChoiceValue::Integer(value),
+    };
+    let choices = [choice("Dog", "Chien", 1), choice("Cat", "Chat", 2)];
+
+    let matcher: FuzzyMatcher = choices.iter().cloned().collect();
+    assert_eq!(names(matcher.matches("ch")), ["Dog", "Cat"]);
+
+    let matcher = matcher.locale("fr");
+    assert_eq!(names(matcher.matches("chat")), ["Cat"]);
+    assert_eq!(names(matcher.matches("dog")), ["Dog"]);
+
+    let matcher = FuzzyMatcher::new(choices).locale("de");
+    assert_eq!(names(matcher.matches("ch")), Vec::<String>::new());
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use twilight_interactions::{
+    command::{CommandModel, CommandOption, CreateCommand, CreateOption},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::command::CommandOptionType;
+
+use common::{option, string_input};
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+enum Tag {
+    #[option(name = "Bug", value = "bug")]
+    Bug,
+    #[option(name = "Feature", value = "feature")]
+    Feature,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "label", desc = "Label an issue")]
+struct LabelCommand {
+    /// Tags to add
+    #[command(min_items = 1, max_items = 2)]
+    tags: Vec<Tag>,
+    /// Comments on the tags
+    #[command(separator = ";", max_length = 5)]
+    notes: Option<Vec<String>>,
+    /// Priorities of the tags
+    #[command(min_value = 1, max_value = 5)]
+    priorities: Option<Vec<i64>>,
+    /// Whether the tags are pinned
+    pinned: Option<Vec<bool>>,
+}
+
+fn parse(options: &[(&str, &str)]) -> Result<LabelCommand, ParseError> {
+    LabelCommand::from_interaction(string_input(options))
+}
+
+fn option_error(field: &str, kind: ParseOptionErrorType) -> ParseError {
+    ParseError::Option(ParseOptionError {
+        field: field.into(),
+        kind,
+    })
+}
+
+#[test]
+fn test_list_option() {
+    assert_eq!(
+        parse(&[("tags", "bug, feature,"), ("notes", "a, b;c")]),
+        Ok(LabelCommand {
+            tags: vec![Tag::Bug, Tag::Feature],
+            notes: Some(vec!["a, b".into(), "c".into()]),
+            priorities: None,
+            pinned: None,
+        })
+    );
+
+    assert_eq!(
+        parse(&[("tags", "bug, docs")]),
+        Err(option_error(
+            "tags",
+            ParseOptionErrorType::InvalidItem {
+                index: 1,
+                kind: Box::new(ParseOptionErrorType::InvalidChoice("docs".into()))
+            }
+        ))
+    );
+    assert_eq!(
+        parse(&[("tags", "bug"), ("notes", "short;too long")]),
+        Err(option_error(
+            "notes",
+            ParseOptionErrorType::InvalidItem {
+                index: 1,
+                kind: Box::new(ParseOptionErrorType::StringLengthOutOfRange(
+                    "too long".into()
+                ))
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_list_typed_items() {
+    assert_eq!(
+        parse(&[
+            ("tags", "bug"),
+            ("priorities", "1, 5"),
+            ("pinned", "true,false")
+        ]),
+        Ok(LabelCommand {
+            tags: vec![Tag::Bug],
+            notes: None,
+            priorities: Some(vec![1, 5]),
+            pinned: Some(vec![true, false]),
+        })
+    );
+
+    // The index is the position of the item in the input, including empty items
+    assert_eq!(
+        parse(&[("tags", "bug"), ("priorities", "1,,high")]),
+        Err(option_error(
+            "priorities",
+            ParseOptionErrorType::InvalidItem {
+                index: 2,
+                kind: Box::new(ParseOptionErrorType::ParseFailed(
+                    "invalid digit found in string".into()
+                ))
+            }
+        ))
+    );
+    assert_eq!(
+        parse(&[("tags", "bug"), ("priorities", "3, 6")]),
+        Err(option_error(
+            "priorities",
+            ParseOptionErrorType::InvalidItem {
+                index: 1,
+                kind: Box::new(ParseOptionErrorType::IntegerOutOfRange(6))
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_list_item_count() {
+    assert_eq!(
+        parse(&[("tags", " , ")]),
+        Err(option_error(
+            "tags",
+            ParseOptionErrorType::ItemCountOutOfRange(0)
+        ))
+    );
+    assert_eq!(
+        parse(&[("tags", "bug, feature, bug")]),
+        Err(option_error(
+            "tags",
+            ParseOptionErrorType::ItemCountOutOfRange(3)
+        ))
+    );
+}
+
+#[test]
+fn test_list_create_option() {
+    let kind = CommandOptionType::String;
+
+    assert_eq!(
+        LabelCommand::create_command().options,
+        vec![
+            option("tags", "Tags to add", kind, true),
+            option("notes", "Comments on the tags", kind, false),
+            option("priorities", "Priorities of the tags", kind, false),
+            option("pinned", "Whether the tags are pinned", kind, false),
+        ]
+    );
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use std::{borrow::Cow, collections::HashMap};
+
+use twilight_interactions::{
+    command::{CommandInputData, CommandModel, ResolvedMember},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{
+    application::interaction::{
+        application_command::CommandOptionValue, InteractionDataResolved, InteractionMember,
+    },
+    guild::{MemberFlags, Permissions, Role, RoleColors, RoleFlags},
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+use common::{input, user};
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct KickCommand {
+    member: ResolvedMember,
+}
+
+fn member(roles: Vec<Id<RoleMarker>>, nick: Option<&str>) -> InteractionMember {
+    InteractionMember {
+        joined_at: Some(Timestamp::from_secs(1609455600).unwrap()),
+        nick: nick.map(Into::into),
+        premium_since: None,
+        roles,
+        avatar: None,
+        communication_disabled_until: Some(Timestamp::from_secs(1609455600).unwrap()),
+        pending: false,
+        permissions: Permissions::KICK_MEMBERS,
+        flags: MemberFlags::empty(),
+        avatar_decoration_data: None,
+        banner: None,
+    }
+}
+
+#[allow(deprecated)]
+fn role(id: u64, position: i64) -> Role {
+    Role {
+        color: 0,
+        colors: RoleColors {
+            primary_color: 0,
+            secondary_color: None,
+            tertiary_color: None,
+        },
+        hoist: false,
+        icon: None,
+        id: Id::new(id),
+        managed: false,
+        mentionable: false,
+        name: format!("role {id}"),
+        permissions: Permissions::empty(),
+        position,
+        flags: RoleFlags::empty(),
+        tags: None,
+        unicode_emoji: None,
+    }
+}
+
+#[test]
+fn test_resolved_member() {
+    let user_id = Id::new(1);
+    let other_id = Id::new(2);
+
+    let resolved = InteractionDataResolved {
+        channels: HashMap::new(),
+        members: HashMap::from([(user_id, member(vec![Id::new(10)], Some("nick")))]),
+        roles: HashMap::new(),
+        users: HashMap::from([
+            (user_id, user(user_id, "someone")),
+            (other_id, user(other_id, "other")),
+        ]),
+        messages: HashMap::new(),
+        attachments: HashMap::new(),
+    };
+
+    let parse = |id: Id<UserMarker>| {
+        KickCommand::from_interaction(CommandInputData {
+            resolved: Some(Cow::Borrowed(&resolved)),
+            ..input(vec![("member", CommandOptionValue::User(id))])
+        })
+    };
+
+    let member = parse(user_id).unwrap().member;
+    assert_eq!(member.id(), user_id);
+    assert_eq!(member.nick(), Some("nick"));
+    assert_eq!(member.display_name(), "nick");
+    assert_eq!(member.roles(), &[Id::new(10)]);
+    assert_eq!(member.permissions(), Permissions::KICK_MEMBERS);
+    assert!(!member.is_timed_out());
+
+    assert_eq!(
+        parse(other_id),
+        Err(ParseError::Option(ParseOptionError {
+            field: "member".into(),
+            kind: ParseOptionErrorType::MemberNotFound(other_id)
+        }))
+    );
+}
+
+#[test]
+fn test_member_hierarchy() {
+    let roles = [role(10, 5), role(11, 3), role(12, 3), role(13, 1)];
+    let new_member = |id: u64, roles: Vec<u64>| ResolvedMember {
+        user: user(Id::new(id), "someone"),
+        member: member(roles.into_iter().map(Id::new).collect(), None),
+    };
+
+    let moderator = new_member(1, vec![13, 10]);
+    let helper = new_member(2, vec![12]);
+    let trial = new_member(3, vec![11]);
+    let everyone = new_member(4, vec![]);
+
+    assert_eq!(moderator.highest_role(&roles), Some(&roles[0]));
+    assert_eq!(everyone.highest_role(&roles), None);
+
+    assert!(moderator.outranks(&helper, &roles));
+    assert!(!helper.outranks(&moderator, &roles));
+    // Roles with the same position are ordered by ID
+    assert!(trial.outranks(&helper, &roles));
+    assert!(helper.outranks(&everyone, &roles));
+    assert!(!everyone.outranks(&everyone, &roles));
+
+    assert!(moderator.outranks_role(&roles[1], &roles));
+    assert!(!helper.outranks_role(&roles[1], &roles));
+    assert!(!everyone.outranks_role(&roles[3], &roles));
+}
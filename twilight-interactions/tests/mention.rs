@@ -0,0 +1,102 @@
+mod common;
+
+use std::{borrow::Cow, collections::HashMap};
+
+use twilight_interactions::{
+    command::{AnyChannel, AnyRole, AnyUser, CommandInputData, CommandModel, ResolvedUser},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{application::interaction::InteractionDataResolved, id::Id};
+
+use common::{string_input, user};
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct BanCommand {
+    user: AnyUser,
+    role: Option<AnyRole>,
+    channel: Option<AnyChannel>,
+}
+
+#[test]
+fn test_parse_mentions() {
+    for user in ["123", "<@123>", "<@!123>", " 123 "] {
+        let command = BanCommand::from_interaction(string_input(&[("user", user)])).unwrap();
+        assert_eq!(
+            command.user,
+            AnyUser {
+                id: Id::new(123),
+                resolved: None
+            }
+        );
+    }
+
+    let command = BanCommand::from_interaction(string_input(&[
+        ("user", "1"),
+        ("role", "<@&2>"),
+        ("channel", "<#3>"),
+    ]))
+    .unwrap();
+
+    assert_eq!(command.role.unwrap().id, Id::new(2));
+    assert_eq!(command.channel.as_ref().unwrap().id, Id::new(3));
+    assert_eq!(command.channel.unwrap().to_string(), "<#3>");
+}
+
+#[test]
+fn test_parse_invalid_mentions() {
+    for (name, value) in [
+        ("user", "someone"),
+        ("user", "<@&123>"),
+        ("user", "<#123>"),
+        ("user", "0"),
+        ("user", "+123"),
+        ("role", "<@123>"),
+        ("channel", "<@123>"),
+    ] {
+        let options = [("user", "1"), (name, value)];
+        let options = if name == "user" {
+            &options[1..]
+        } else {
+            &options[..]
+        };
+
+        assert_eq!(
+            BanCommand::from_interaction(string_input(options)),
+            Err(ParseError::Option(ParseOptionError {
+                field: name.into(),
+                kind: ParseOptionErrorType::InvalidMention(value.into())
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_resolve_user() {
+    let user_id = Id::new(123);
+    let user = user(user_id, "someone");
+
+    let resolved = InteractionDataResolved {
+        channels: HashMap::new(),
+        members: HashMap::new(),
+        roles: HashMap::new(),
+        users: HashMap::from([(user_id, user.clone())]),
+        messages: HashMap::new(),
+        attachments: HashMap::new(),
+    };
+
+    let command = BanCommand::from_interaction(CommandInputData {
+        resolved: Some(Cow::Owned(resolved)),
+        ..string_input(&[("user", "<@123>")])
+    });
+
+    assert_eq!(
+        command.unwrap().user,
+        AnyUser {
+            id: user_id,
+            resolved: Some(ResolvedUser {
+                resolved: user,
+                member: None
+            })
+        }
+    );
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use twilight_interactions::{
+    command::{CommandContext, CommandModel, MessageLink},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{application::interaction::application_command::CommandOptionValue, id::Id};
+
+use common::{string_input, subcommand_input};
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct QuoteCommand {
+    message: MessageLink,
+    #[command(same_guild = true)]
+    reply_to: Option<MessageLink>,
+}
+
+#[test]
+fn test_parse_message_link() {
+    let guild = MessageLink {
+        guild_id: Some(Id::new(1)),
+        channel_id: Id::new(2),
+        message_id: Id::new(3),
+    };
+
+    for link in [
+        "https://discord.com/channels/1/2/3",
+        "https://ptb.discord.com/channels/1/2/3",
+        "https://canary.discord.com/channels/1/2/3",
+        "https://discordapp.com/channels/1/2/3",
+        " <https://discord.com/channels/1/2/3> ",
+    ] {
+        assert_eq!(link.parse(), Ok(guild));
+    }
+
+    let direct = "https://discord.com/channels/@me/2/3".parse::<MessageLink>();
+    assert_eq!(
+        direct,
+        Ok(MessageLink {
+            guild_id: None,
+            ..guild
+        })
+    );
+
+    assert_eq!(guild.to_string(), "https://discord.com/channels/1/2/3");
+    assert_eq!(
+        direct.unwrap().to_string(),
+        "https://discord.com/channels/@me/2/3"
+    );
+}
+
+#[test]
+fn test_parse_invalid_message_link() {
+    for link in [
+        "",
+        "https://discord.com/channels/1/2",
+        "https://discord.com/channels/1/2/3/4",
+        "https://discord.com/channels/1/0/3",
+        "https://discord.com/channels/x/2/3",
+        "https://example.com/channels/1/2/3",
+        "https://beta.discord.com/channels/1/2/3",
+        "discord.com/channels/1/2/3",
+    ] {
+        assert_eq!(
+            link.parse::<MessageLink>(),
+            Err(ParseOptionErrorType::InvalidMessageLink(link.into()))
+        );
+    }
+}
+
+#[test]
+fn test_message_link_same_guild() {
+    let parse = |reply_to: &str, guild_id: Option<u64>| {
+        let context = CommandContext::new(guild_id.map(Id::new));
+
+        QuoteCommand::from_interaction_with(
+            string_input(&[
+                ("message", "https://discord.com/channels/9/9/9"),
+                ("reply_to", reply_to),
+            ]),
+            &context,
+        )
+    };
+
+    let command = parse("https://discord.com/channels/1/2/3", Some(1)).unwrap();
+    assert_eq!(command.message.guild_id, Some(Id::new(9)));
+    assert_eq!(command.reply_to.unwrap().guild_id, Some(Id::new(1)));
+
+    assert!(parse("https://discord.com/channels/@me/2/3", None).is_ok());
+
+    assert_eq!(
+        parse("https://discord.com/channels/2/2/3", Some(1)),
+        Err(ParseError::Option(ParseOptionError {
+            field: "reply_to".into(),
+            kind: ParseOptionErrorType::GuildMismatch(Some(Id::new(2)))
+        }))
+    );
+    assert_eq!(
+        parse("https://discord.com/channels/@me/2/3", Some(1)),
+        Err(ParseError::Option(ParseOptionError {
+            field: "reply_to".into(),
+            kind: ParseOptionErrorType::GuildMismatch(None)
+        }))
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+enum MessageCommand {
+    #[command(name = "quote")]
+    Quote(QuoteCommand),
+}
+
+#[test]
+fn test_message_link_subcommand_context() {
+    let data = subcommand_input(
+        "quote",
+        vec![
+            (
+                "message",
+                CommandOptionValue::String("https://discord.com/channels/9/9/9".into()),
+            ),
+            (
+                "reply_to",
+                CommandOptionValue::String("https://discord.com/channels/2/2/3".into()),
+            ),
+        ],
+    );
+
+    let context = CommandContext::new(Some(Id::new(2)));
+    assert!(MessageCommand::from_interaction_with(data.clone(), &context).is_ok());
+
+    let context = CommandContext::new(Some(Id::new(1)));
+    assert_eq!(
+        MessageCommand::from_interaction_with(data.clone(), &context),
+        Err(ParseError::Option(ParseOptionError {
+            field: "reply_to".into(),
+            kind: ParseOptionErrorType::GuildMismatch(Some(Id::new(2)))
+        }))
+    );
+
+    assert!(MessageCommand::from_interaction(data).is_ok());
+}
+
+#[test]
+fn test_message_link_unknown_context() {
+    let data = string_input(&[
+        ("message", "https://discord.com/channels/9/9/9"),
+        ("reply_to", "https://discord.com/channels/@me/2/3"),
+    ]);
+
+    let command = QuoteCommand::from_interaction(data.clone()).unwrap();
+    assert_eq!(command.reply_to.unwrap().guild_id, None);
+
+    let command = QuoteCommand::from_interaction_with(data, &CommandContext::default()).unwrap();
+    assert_eq!(command.reply_to.unwrap().guild_id, None);
+}
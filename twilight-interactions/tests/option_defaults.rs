@@ -0,0 +1,151 @@
+mod common;
+
+use twilight_interactions::{
+    command::{
+        internal::{CommandOptionData, CreateOptionData},
+        CommandModel, CommandOption, CreateCommand, CreateOption, DescLocalizations,
+    },
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::{
+    command::{CommandOption as TwilightCommandOption, CommandOptionType},
+    interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+};
+
+use common::{option, string_input};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Reason(String);
+
+fn reason_data() -> CommandOptionData {
+    CommandOptionData {
+        min_length: Some(3),
+        max_length: Some(512),
+        ..Default::default()
+    }
+}
+
+impl CommandOption for Reason {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        String::from_option(value, data, resolved).map(Self)
+    }
+
+    fn default_data() -> CommandOptionData {
+        reason_data()
+    }
+}
+
+impl CreateOption for Reason {
+    fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+        String::create_option(data)
+    }
+
+    fn default_data() -> CommandOptionData {
+        reason_data()
+    }
+
+    fn default_description() -> Option<DescLocalizations> {
+        Some(DescLocalizations::new(
+            "Reason of the action",
+            [("fr", "Raison de l'action")],
+        ))
+    }
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "ban", desc = "Ban a user")]
+struct BanCommand {
+    reason: Reason,
+    /// Note shown to the user
+    #[command(max_length = 100)]
+    note: Option<Reason>,
+}
+
+fn parse(options: &[(&str, &str)]) -> Result<BanCommand, ParseError> {
+    BanCommand::from_interaction(string_input(options))
+}
+
+#[test]
+fn test_option_defaults_parse() {
+    assert_eq!(
+        parse(&[("reason", "spam"), ("note", "please stop")]),
+        Ok(BanCommand {
+            reason: Reason("spam".into()),
+            note: Some(Reason("please stop".into())),
+        })
+    );
+
+    let long = "a".repeat(200);
+    assert_eq!(
+        parse(&[("reason", &long)]).map(|command| command.reason),
+        Ok(Reason(long.clone()))
+    );
+    assert_eq!(
+        parse(&[("reason", "spam"), ("note", &long)]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "note".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange(long)
+        }))
+    );
+
+    // Defaults not overridden by attributes are kept
+    assert_eq!(
+        parse(&[("reason", "spam"), ("note", "no")]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "note".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange("no".into())
+        }))
+    );
+}
+
+#[test]
+fn test_option_defaults_create() {
+    let options = BanCommand::create_command().options;
+
+    assert_eq!(
+        options,
+        vec![
+            TwilightCommandOption {
+                description_localizations: Some(
+                    [("fr".into(), "Raison de l'action".into())].into()
+                ),
+                max_length: Some(512),
+                min_length: Some(3),
+                ..option(
+                    "reason",
+                    "Reason of the action",
+                    CommandOptionType::String,
+                    true,
+                )
+            },
+            TwilightCommandOption {
+                max_length: Some(100),
+                min_length: Some(3),
+                ..option(
+                    "note",
+                    "Note shown to the user",
+                    CommandOptionType::String,
+                    false,
+                )
+            },
+        ]
+    );
+}
+
+#[derive(CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "kick", desc = "Kick a user")]
+struct KickCommand {
+    reason: String,
+}
+
+#[test]
+#[should_panic(
+    expected = "option `reason` has no description (documentation comment or `desc` attribute) and its type has no default description"
+)]
+fn test_option_defaults_missing_description() {
+    KickCommand::create_command();
+}
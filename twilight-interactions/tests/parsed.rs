@@ -0,0 +1,65 @@
+mod common;
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand, Parsed},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::command::CommandOptionType;
+
+use common::{option, string_input};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "ping", desc = "Ping an address")]
+struct PingCommand {
+    /// Address to ping
+    address: Parsed<IpAddr>,
+    /// Number of packets
+    count: Option<Parsed<u8>>,
+}
+
+fn parse(address: &str) -> Result<PingCommand, ParseError> {
+    PingCommand::from_interaction(string_input(&[("address", address)]))
+}
+
+#[test]
+fn test_parsed_option() {
+    assert_eq!(
+        parse("127.0.0.1"),
+        Ok(PingCommand {
+            address: Parsed(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            count: None,
+        })
+    );
+
+    let error = "localhost".parse::<IpAddr>().unwrap_err().to_string();
+    assert_eq!(
+        parse("localhost"),
+        Err(ParseError::Option(ParseOptionError {
+            field: "address".into(),
+            kind: ParseOptionErrorType::ParseFailed(error)
+        }))
+    );
+}
+
+#[test]
+fn test_parsed_create_option() {
+    assert_eq!(
+        PingCommand::create_command().options,
+        vec![
+            option(
+                "address",
+                "Address to ping",
+                CommandOptionType::String,
+                true
+            ),
+            option(
+                "count",
+                "Number of packets",
+                CommandOptionType::String,
+                false
+            ),
+        ]
+    );
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::collections::HashMap;
+
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand, NameLocalizations},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{
+    application::{
+        command::{CommandOption, CommandOptionType},
+        interaction::application_command::CommandOptionValue,
+    },
+    id::{marker::UserMarker, Id},
+};
+
+use common::{input, option};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "invite", desc = "Invite users")]
+struct InviteCommand {
+    /// User to invite
+    #[command(repeat = 3, min_items = 1, name_localizations = "user_name")]
+    user: Vec<Id<UserMarker>>,
+    /// Invitation message
+    message: Option<String>,
+}
+
+fn user_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "utilisateur")])
+}
+
+fn parse(options: &[(&str, u64)]) -> Result<InviteCommand, ParseError> {
+    InviteCommand::from_interaction(input(
+        options
+            .iter()
+            .map(|(name, id)| (*name, CommandOptionValue::User(Id::new(*id))))
+            .collect(),
+    ))
+}
+
+#[test]
+fn test_repeated_options() {
+    assert_eq!(
+        parse(&[("user1", 1), ("user3", 3)]),
+        Ok(InviteCommand {
+            user: vec![Id::new(1), Id::new(3)],
+            message: None,
+        })
+    );
+
+    assert_eq!(
+        parse(&[("user2", 2)]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "user1".into(),
+            kind: ParseOptionErrorType::RequiredField
+        }))
+    );
+
+    assert_eq!(
+        parse(&[("user1", 1), ("user4", 4)]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "user4".into(),
+            kind: ParseOptionErrorType::UnknownField
+        }))
+    );
+}
+
+#[test]
+fn test_repeated_create_options() {
+    let user = |index: u8| CommandOption {
+        name_localizations: Some(HashMap::from([(
+            "fr".into(),
+            format!("utilisateur{index}"),
+        )])),
+        ..option(
+            &format!("user{index}"),
+            "User to invite",
+            CommandOptionType::User,
+            index == 1,
+        )
+    };
+
+    let message = option(
+        "message",
+        "Invitation message",
+        CommandOptionType::String,
+        false,
+    );
+
+    assert_eq!(
+        InviteCommand::create_command().options,
+        vec![user(1), user(2), user(3), message]
+    );
+}
@@ -1,16 +1,20 @@
+mod common;
+
 use std::collections::HashMap;
 
 use twilight_interactions::command::{
-    ApplicationCommandData, CommandInputData, CommandModel, CreateCommand, DescLocalizations,
+    ApplicationCommandData, CommandModel, CreateCommand, DescLocalizations, NameLocalizations,
 };
 use twilight_model::{
     application::{
         command::{CommandOption, CommandOptionType},
-        interaction::application_command::{CommandDataOption, CommandOptionValue},
+        interaction::application_command::CommandOptionValue,
     },
     guild::Permissions,
 };
 
+use common::{input, option, subcommand_input};
+
 #[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
 #[command(name = "one", desc = "Command one")]
 struct CommandOne {
@@ -64,20 +68,10 @@ fn subcommand_permissions() -> Permissions {
 
 #[test]
 fn test_subcommand_model() {
-    let subcommand_options = vec![CommandDataOption {
-        name: "option".into(),
-        value: CommandOptionValue::String("test".into()),
-    }];
-
-    let command_options = vec![CommandDataOption {
-        name: "one".into(),
-        value: CommandOptionValue::SubCommand(subcommand_options),
-    }];
-
-    let data = CommandInputData {
-        options: command_options,
-        resolved: None,
-    };
+    let data = subcommand_input(
+        "one",
+        vec![("option", CommandOptionValue::String("test".into()))],
+    );
 
     let result = SubCommand::from_interaction(data).unwrap();
 
@@ -91,25 +85,14 @@ fn test_subcommand_model() {
 
 #[test]
 fn test_subcommand_group_model() {
-    let subcommand_options = vec![CommandDataOption {
-        name: "option".into(),
-        value: CommandOptionValue::String("test".into()),
-    }];
-
-    let subcommand_group_options = vec![CommandDataOption {
-        name: "three".into(),
-        value: CommandOptionValue::SubCommand(subcommand_options),
-    }];
-
-    let command_options = vec![CommandDataOption {
-        name: "group".into(),
-        value: CommandOptionValue::SubCommandGroup(subcommand_group_options),
-    }];
-
-    let data = CommandInputData {
-        options: command_options,
-        resolved: None,
-    };
+    let subcommand = subcommand_input(
+        "three",
+        vec![("option", CommandOptionValue::String("test".into()))],
+    );
+    let data = input(vec![(
+        "group",
+        CommandOptionValue::SubCommandGroup(subcommand.options),
+    )]);
 
     let result = SubCommand::from_interaction(data).unwrap();
 
@@ -123,91 +106,47 @@ fn test_subcommand_group_model() {
 
 #[test]
 fn test_create_subcommand() {
-    let command_options = vec![CommandOption {
-        autocomplete: Some(false),
-        channel_types: None,
-        choices: None,
-        description: "An option".into(),
-        description_localizations: None,
-        kind: CommandOptionType::String,
-        max_length: None,
-        max_value: None,
-        min_length: None,
-        min_value: None,
-        name: "option".into(),
-        name_localizations: None,
-        options: None,
-        required: Some(true),
-    }];
+    let nested = |name: &str, description: &str, kind, options| CommandOption {
+        options: Some(options),
+        required: None,
+        ..option(name, description, kind, false)
+    };
+
+    let command_options = vec![option(
+        "option",
+        "An option",
+        CommandOptionType::String,
+        true,
+    )];
 
     let subcommand_group = vec![
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Command two".into(),
-            description_localizations: None,
-            kind: CommandOptionType::SubCommand,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "two".into(),
-            name_localizations: None,
-            options: Some(command_options.clone()),
-            required: None,
-        },
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Command three".into(),
-            description_localizations: None,
-            kind: CommandOptionType::SubCommand,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "three".into(),
-            name_localizations: None,
-            options: Some(command_options.clone()),
-            required: None,
-        },
+        nested(
+            "two",
+            "Command two",
+            CommandOptionType::SubCommand,
+            command_options.clone(),
+        ),
+        nested(
+            "three",
+            "Command three",
+            CommandOptionType::SubCommand,
+            command_options.clone(),
+        ),
     ];
 
     let subcommand = vec![
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Command one".into(),
-            description_localizations: None,
-            kind: CommandOptionType::SubCommand,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "one".into(),
-            name_localizations: None,
-            options: Some(command_options),
-            required: None,
-        },
-        CommandOption {
-            autocomplete: Some(false),
-            channel_types: None,
-            choices: None,
-            description: "Command group".into(),
-            description_localizations: None,
-            kind: CommandOptionType::SubCommandGroup,
-            max_length: None,
-            max_value: None,
-            min_length: None,
-            min_value: None,
-            name: "group".into(),
-            name_localizations: None,
-            options: Some(subcommand_group),
-            required: None,
-        },
+        nested(
+            "one",
+            "Command one",
+            CommandOptionType::SubCommand,
+            command_options,
+        ),
+        nested(
+            "group",
+            "Command group",
+            CommandOptionType::SubCommandGroup,
+            subcommand_group,
+        ),
     ];
 
     #[allow(deprecated)]
@@ -227,3 +166,158 @@ fn test_create_subcommand() {
 
     assert_eq!(SubCommand::create_command(), expected);
 }
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "config", desc = "Configure the bot")]
+enum ConfigCommand {
+    /// Add an amount
+    #[command(name = "add")]
+    Add {
+        /// Amount to add
+        amount: i64,
+        /// Optional note
+        note: Option<String>,
+    },
+    #[command(name = "reset", desc = "Reset the configuration")]
+    Reset,
+    #[command(name = "one")]
+    One(CommandOne),
+}
+
+#[test]
+fn test_inline_subcommand_model() {
+    let data = subcommand_input("add", vec![("amount", CommandOptionValue::Integer(5))]);
+    assert_eq!(
+        ConfigCommand::from_interaction(data).unwrap(),
+        ConfigCommand::Add {
+            amount: 5,
+            note: None
+        }
+    );
+
+    let data = subcommand_input("reset", Vec::new());
+    assert_eq!(
+        ConfigCommand::from_interaction(data).unwrap(),
+        ConfigCommand::Reset
+    );
+
+    let data = subcommand_input("add", Vec::new());
+    assert!(ConfigCommand::from_interaction(data).is_err());
+}
+
+#[test]
+fn test_create_inline_subcommand() {
+    let subcommand = |name: &str, description: &str, options| CommandOption {
+        options: Some(options),
+        required: None,
+        ..option(name, description, CommandOptionType::SubCommand, false)
+    };
+
+    let options = ConfigCommand::create_command().options;
+    assert_eq!(
+        options,
+        vec![
+            subcommand(
+                "add",
+                "Add an amount",
+                vec![
+                    option("amount", "Amount to add", CommandOptionType::Integer, true),
+                    option("note", "Optional note", CommandOptionType::String, false),
+                ]
+            ),
+            subcommand("reset", "Reset the configuration", Vec::new()),
+            subcommand(
+                "one",
+                "Command one",
+                vec![option(
+                    "option",
+                    "An option",
+                    CommandOptionType::String,
+                    true
+                )]
+            ),
+        ]
+    );
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "alias", desc = "Aliases of other commands")]
+enum AliasCommand {
+    /// First alias of command one
+    #[command(name = "first")]
+    First(CommandOne),
+    #[command(
+        name = "second",
+        name_localizations = "second_name",
+        desc_localizations = "second_desc"
+    )]
+    Second(CommandOne),
+    #[command(name = "group")]
+    Group(SubCommandGroup),
+    #[command(name = "localized")]
+    Localized(LocalizedCommand),
+    #[command(name = "renamed")]
+    Renamed(LocalizedCommand),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(
+    name = "localized",
+    desc = "Localized command",
+    name_localizations = "localized_name"
+)]
+struct LocalizedCommand;
+
+fn localized_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "localise")])
+}
+
+fn second_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "deuxieme")])
+}
+
+fn second_desc() -> DescLocalizations {
+    DescLocalizations::new("Second alias", [("fr", "Deuxième alias")])
+}
+
+#[test]
+fn test_subcommand_variant_attributes() {
+    let options = AliasCommand::create_command().options;
+
+    assert_eq!(options[0].name, "first");
+    assert_eq!(options[0].description, "First alias of command one");
+    assert_eq!(options[0].description_localizations, None);
+
+    assert_eq!(options[1].name, "second");
+    assert_eq!(
+        options[1].name_localizations,
+        Some(HashMap::from([("fr".into(), "deuxieme".into())]))
+    );
+    assert_eq!(options[1].description, "Second alias");
+    assert_eq!(
+        options[1].description_localizations,
+        Some(HashMap::from([("fr".into(), "Deuxième alias".into())]))
+    );
+
+    // Metadata of the inner command is kept without variant attributes
+    assert_eq!(options[2].name, "group");
+    assert_eq!(options[2].description, "Command group");
+    assert_eq!(options[2].kind, CommandOptionType::SubCommandGroup);
+
+    // Localized names are dropped when the variant renames the command
+    assert_eq!(
+        options[3].name_localizations,
+        Some(HashMap::from([("fr".into(), "localise".into())]))
+    );
+    assert_eq!(options[4].name, "renamed");
+    assert_eq!(options[4].name_localizations, None);
+}
+
+#[test]
+fn test_subcommand_nesting_depth() {
+    assert_eq!(CommandOne::NESTING_DEPTH, 0);
+    assert_eq!(ConfigCommand::NESTING_DEPTH, 1);
+    assert_eq!(SubCommandGroup::NESTING_DEPTH, 1);
+    assert_eq!(SubCommand::NESTING_DEPTH, 2);
+    assert_eq!(AliasCommand::NESTING_DEPTH, 2);
+}
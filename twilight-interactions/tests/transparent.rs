@@ -0,0 +1,109 @@
+mod common;
+
+use twilight_interactions::{
+    command::{CommandModel, CommandOption, CreateCommand, CreateOption},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::{
+    command::{CommandOption as TwilightCommandOption, CommandOptionType, CommandOptionValue},
+    interaction::application_command::CommandOptionValue as InteractionOptionValue,
+};
+
+use common::{input, option};
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+#[option(transparent, min_value = 0, max_value = 100, desc = "A percentage")]
+struct Percentage(i64);
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+#[option(transparent, max_length = 32, validate = "validate_username")]
+struct Username(String);
+
+fn validate_username(value: &str) -> Result<(), &'static str> {
+    if value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(())
+    } else {
+        Err("invalid username")
+    }
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "volume", desc = "Set the volume")]
+struct VolumeCommand {
+    level: Percentage,
+    /// User to set the volume for
+    #[command(max_length = 16)]
+    user: Option<Username>,
+}
+
+fn parse(options: Vec<(&str, InteractionOptionValue)>) -> Result<VolumeCommand, ParseError> {
+    VolumeCommand::from_interaction(input(options))
+}
+
+#[test]
+fn test_transparent_parse() {
+    assert_eq!(
+        parse(vec![
+            ("level", InteractionOptionValue::Integer(50)),
+            ("user", InteractionOptionValue::String("some_user".into())),
+        ]),
+        Ok(VolumeCommand {
+            level: Percentage(50),
+            user: Some(Username("some_user".into())),
+        })
+    );
+
+    assert_eq!(
+        parse(vec![("level", InteractionOptionValue::Integer(150))]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "level".into(),
+            kind: ParseOptionErrorType::IntegerOutOfRange(150)
+        }))
+    );
+    assert_eq!(
+        parse(vec![
+            ("level", InteractionOptionValue::Integer(50)),
+            ("user", InteractionOptionValue::String("some user".into())),
+        ]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "user".into(),
+            kind: ParseOptionErrorType::Custom("invalid username".into())
+        }))
+    );
+
+    // Field attributes override the struct constraints
+    let long = "a".repeat(20);
+    assert_eq!(
+        parse(vec![
+            ("level", InteractionOptionValue::Integer(50)),
+            ("user", InteractionOptionValue::String(long.clone())),
+        ]),
+        Err(ParseError::Option(ParseOptionError {
+            field: "user".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange(long)
+        }))
+    );
+}
+
+#[test]
+fn test_transparent_create() {
+    assert_eq!(
+        VolumeCommand::create_command().options,
+        vec![
+            TwilightCommandOption {
+                max_value: Some(CommandOptionValue::Integer(100)),
+                min_value: Some(CommandOptionValue::Integer(0)),
+                ..option("level", "A percentage", CommandOptionType::Integer, true)
+            },
+            TwilightCommandOption {
+                max_length: Some(16),
+                ..option(
+                    "user",
+                    "User to set the volume for",
+                    CommandOptionType::String,
+                    false,
+                )
+            },
+        ]
+    );
+}